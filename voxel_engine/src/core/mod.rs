@@ -6,7 +6,9 @@ mod window;
 use anyhow::Result;
 use std::time::{Duration, Instant};
 use log::{info, trace};
-use crate::graphics::Renderer;
+use glam::Vec3;
+use crate::graphics::{Camera3d, Renderer};
+use crate::voxel::{manage_chunk_loading, ChunkManager};
 
 pub use window::Window;
 
@@ -16,6 +18,10 @@ pub struct Engine {
     window: Window,
     /// Rendering system
     renderer: Option<Renderer>,
+    /// Tracks which chunks are loaded and feeds the renderer's `MeshPool`
+    chunk_manager: ChunkManager,
+    /// Placeholder camera feeding `update_camera` each frame, until a real player/camera exists
+    camera: Camera3d,
     /// Whether the engine is running
     running: bool,
     /// Target frames per second
@@ -38,6 +44,8 @@ impl Engine {
         let mut engine = Self {
             window,
             renderer: None,
+            chunk_manager: ChunkManager::new(3),
+            camera: Camera3d::new(Vec3::new(0.0, 10.0, 20.0), Vec3::ZERO),
             running: false,
             target_fps: 60,
             frame_time: Duration::from_secs_f32(1.0 / 60.0),
@@ -91,13 +99,20 @@ impl Engine {
     
     /// Updates the game state for a frame
     fn update(&mut self, delta_time: Duration) -> Result<()> {
-        // We'll add more update logic as we build the engine
+        if let Some(renderer) = &mut self.renderer {
+            // TODO: derive this from an actual player/camera position once one exists
+            let camera_chunk_pos = (0, 0, 0);
+            manage_chunk_loading(&mut self.chunk_manager, camera_chunk_pos, renderer.device(), renderer.mesh_pool_mut());
+        }
         Ok(())
     }
-    
+
     /// Renders a frame
     fn render(&mut self) -> Result<()> {
         if let Some(renderer) = &mut self.renderer {
+            renderer.set_camera_chunk_origin(self.chunk_manager.camera_chunk_origin);
+            let view_proj = self.camera.build_view_projection_matrix(renderer.aspect_ratio());
+            renderer.update_camera(view_proj.to_cols_array_2d());
             renderer.render()?;
         }
         Ok(())
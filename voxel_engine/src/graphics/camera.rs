@@ -0,0 +1,59 @@
+// Camera module
+// Holds the 3D camera transform and the GPU-side uniform derived from it
+
+use glam::{Mat4, Vec3};
+
+/// A simple look-at camera; the engine's analogue of a `Camera3d` transform
+pub struct Camera3d {
+    pub eye: Vec3,
+    pub target: Vec3,
+    pub up: Vec3,
+    pub fovy: f32,
+    pub znear: f32,
+    pub zfar: f32,
+}
+
+impl Camera3d {
+    pub fn new(eye: Vec3, target: Vec3) -> Self {
+        Self {
+            eye,
+            target,
+            up: Vec3::Y,
+            fovy: 60f32.to_radians(),
+            znear: 0.1,
+            zfar: 1000.0,
+        }
+    }
+
+    /// Computes the combined view-projection matrix for the given viewport aspect ratio
+    pub fn build_view_projection_matrix(&self, aspect: f32) -> Mat4 {
+        let view = Mat4::look_at_rh(self.eye, self.target, self.up);
+        let proj = Mat4::perspective_rh(self.fovy, aspect, self.znear, self.zfar);
+        proj * view
+    }
+}
+
+/// GPU-side representation of the camera, uploaded to a uniform buffer each frame
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CameraUniform {
+    pub view_proj: [[f32; 4]; 4],
+}
+
+impl CameraUniform {
+    pub fn new() -> Self {
+        Self {
+            view_proj: Mat4::IDENTITY.to_cols_array_2d(),
+        }
+    }
+
+    pub fn update_view_proj(&mut self, camera: &Camera3d, aspect: f32) {
+        self.view_proj = camera.build_view_projection_matrix(aspect).to_cols_array_2d();
+    }
+}
+
+impl Default for CameraUniform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,16 @@
+// Chunk offset module
+// GPU-side representation of a chunk's world position, relative to the camera's current chunk
+
+/// Per-chunk offset uniform; padded to 16 bytes to satisfy std140 vec3 alignment
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ChunkOffsetUniform {
+    pub offset: [f32; 3],
+    pub _padding: f32,
+}
+
+impl ChunkOffsetUniform {
+    pub fn new(offset: [f32; 3]) -> Self {
+        Self { offset, _padding: 0.0 }
+    }
+}
@@ -0,0 +1,104 @@
+// MeshPool module
+// Owns per-chunk GPU vertex/index buffers and bridges CPU-side chunk meshes to the Renderer
+
+use std::collections::HashMap;
+use wgpu::util::DeviceExt;
+use wgpu::Device;
+
+/// Vertex layout shared by all chunk meshes: position, normal, uv, and a texture-array layer
+/// index to match standard voxel shaders
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+    pub tex_index: u32,
+}
+
+impl Vertex {
+    pub fn layout<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress * 2,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress * 2
+                        + std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    shader_location: 3,
+                    format: wgpu::VertexFormat::Uint32,
+                },
+            ],
+        }
+    }
+}
+
+/// Opaque handle to a chunk's uploaded GPU mesh; chunk positions double as the handle since
+/// each chunk has at most one resident mesh at a time
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MeshHandle(pub (i64, i64, i64));
+
+struct GpuMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    index_count: u32,
+}
+
+/// Owns per-chunk vertex/index buffers keyed by chunk position
+#[derive(Default)]
+pub struct MeshPool {
+    meshes: HashMap<(i64, i64, i64), GpuMesh>,
+}
+
+impl MeshPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uploads a chunk mesh to the GPU, replacing any mesh already resident for `pos`
+    pub fn upload(&mut self, device: &Device, pos: (i64, i64, i64), vertices: &[Vertex], indices: &[u32]) -> MeshHandle {
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Chunk Vertex Buffer"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Chunk Index Buffer"),
+            contents: bytemuck::cast_slice(indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        self.meshes.insert(pos, GpuMesh {
+            vertex_buffer,
+            index_buffer,
+            index_count: indices.len() as u32,
+        });
+
+        MeshHandle(pos)
+    }
+
+    /// Frees the GPU buffers for a chunk, if any are resident
+    pub fn remove(&mut self, pos: (i64, i64, i64)) {
+        self.meshes.remove(&pos);
+    }
+
+    /// Iterates all resident meshes, for the renderer to issue draw calls against
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&(i64, i64, i64), &wgpu::Buffer, &wgpu::Buffer, u32)> {
+        self.meshes.iter().map(|(pos, mesh)| (pos, &mesh.vertex_buffer, &mesh.index_buffer, mesh.index_count))
+    }
+}
@@ -0,0 +1,14 @@
+// Graphics module
+// Owns the wgpu renderer and everything needed to turn engine state into pixels
+
+mod camera;
+mod chunk_offset;
+mod mesh_pool;
+mod renderer;
+mod texture_pool;
+
+pub use camera::{Camera3d, CameraUniform};
+pub use chunk_offset::ChunkOffsetUniform;
+pub use mesh_pool::{MeshHandle, MeshPool, Vertex};
+pub use renderer::Renderer;
+pub use texture_pool::TexturePool;
@@ -3,12 +3,27 @@
 
 use anyhow::Result;
 use log::{debug, info};
+use wgpu::util::DeviceExt;
 use wgpu::{
     Adapter, Backends, Device, Instance, Queue, Surface, SurfaceConfiguration,
     TextureUsages, PresentMode, TextureFormat,
 };
 use winit::window::Window;
 
+use super::camera::CameraUniform;
+use super::chunk_offset::ChunkOffsetUniform;
+use super::mesh_pool::{MeshPool, Vertex};
+use super::texture_pool::TexturePool;
+use crate::voxel::{CHUNK_SIZE, VOXEL_SIZE_METERS};
+
+/// Format used for the depth buffer; matched by any pipeline wanting to depth-test against it
+pub const DEPTH_FORMAT: TextureFormat = TextureFormat::Depth32Float;
+
+/// Rounds `value` up to the next multiple of `alignment`
+fn align_to(value: u64, alignment: u64) -> u64 {
+    ((value + alignment - 1) / alignment) * alignment
+}
+
 /// The main renderer that handles GPU communication and drawing
 pub struct Renderer {
     instance: Instance,
@@ -18,6 +33,18 @@ pub struct Renderer {
     queue: Queue,
     config: SurfaceConfiguration,
     size: winit::dpi::PhysicalSize<u32>,
+    depth_texture: wgpu::Texture,
+    depth_view: wgpu::TextureView,
+    camera_uniform: CameraUniform,
+    camera_buffer: wgpu::Buffer,
+    camera_bind_group_layout: wgpu::BindGroupLayout,
+    render_pipeline: wgpu::RenderPipeline,
+    mesh_pool: MeshPool,
+    /// Block texture array bound at `@group(1)`; until a real asset pipeline exists this is a
+    /// small flat-colored placeholder palette keyed by `Voxel::texture_layer`
+    texture_pool: TexturePool,
+    /// The chunk the camera currently occupies; all chunk draw offsets are relative to this
+    camera_chunk_origin: (i64, i64, i64),
 }
 
 impl Renderer {
@@ -79,9 +106,55 @@ impl Renderer {
         };
         
         surface.configure(&device, &config);
-        
+
+        let (depth_texture, depth_view) = Self::create_depth_texture(&device, size.width, size.height);
+
+        let camera_uniform = CameraUniform::new();
+        let camera_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Camera Buffer"),
+            contents: bytemuck::cast_slice(&[camera_uniform]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let camera_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Camera Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<ChunkOffsetUniform>() as u64),
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        // Flat-colored placeholder palette; layer 0 matches `Voxel::Solid` until real block
+        // textures exist
+        let texture_pool = TexturePool::placeholder(&device, &queue, &[[200, 200, 200, 255]]);
+
+        let render_pipeline = Self::create_render_pipeline(
+            &device,
+            format,
+            &camera_bind_group_layout,
+            texture_pool.bind_group_layout(),
+        );
+
         info!("Renderer initialized successfully");
-        
+
         Ok(Self {
             instance,
             surface,
@@ -90,9 +163,114 @@ impl Renderer {
             queue,
             config,
             size,
+            depth_texture,
+            depth_view,
+            camera_uniform,
+            camera_buffer,
+            camera_bind_group_layout,
+            render_pipeline,
+            mesh_pool: MeshPool::new(),
+            texture_pool,
+            camera_chunk_origin: (0, 0, 0),
         })
     }
-    
+
+    /// Updates the chunk the camera currently occupies; subsequent `render` calls compute
+    /// each chunk's draw offset relative to this origin instead of absolute world space
+    pub fn set_camera_chunk_origin(&mut self, origin: (i64, i64, i64)) {
+        self.camera_chunk_origin = origin;
+    }
+
+    /// Builds the main render pipeline: a single vertex/fragment shader pair, matched against
+    /// the renderer's depth buffer, with the camera bind group at `@group(0)` and the block
+    /// texture array at `@group(1)`
+    fn create_render_pipeline(
+        device: &Device,
+        format: TextureFormat,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        texture_bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Main Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shader.wgsl").into()),
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Main Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Main Render Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::layout()],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        })
+    }
+
+    /// Writes a new view-projection matrix into the camera uniform buffer; call before `render`
+    pub fn update_camera(&mut self, view_proj: [[f32; 4]; 4]) {
+        self.camera_uniform.view_proj = view_proj;
+        self.queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[self.camera_uniform]));
+    }
+
+    /// Width/height of the current surface, for `Camera3d::build_view_projection_matrix`'s aspect
+    /// ratio parameter
+    pub fn aspect_ratio(&self) -> f32 {
+        self.size.width as f32 / self.size.height.max(1) as f32
+    }
+
+    /// Creates the depth texture (and its view) at the given dimensions
+    fn create_depth_texture(device: &Device, width: u32, height: u32) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&Default::default());
+        (texture, view)
+    }
+
     /// Handles window resize events
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
@@ -101,8 +279,23 @@ impl Renderer {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
+
+            let (depth_texture, depth_view) = Self::create_depth_texture(&self.device, new_size.width, new_size.height);
+            self.depth_texture = depth_texture;
+            self.depth_view = depth_view;
         }
     }
+
+    /// Returns a reference to the depth buffer's `TextureView`, so pipelines built elsewhere
+    /// can match this renderer's `DepthStencilState`
+    pub fn depth_view(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    /// Returns the mesh pool that owns uploaded chunk buffers
+    pub fn mesh_pool_mut(&mut self) -> &mut MeshPool {
+        &mut self.mesh_pool
+    }
     
     /// Renders a frame
     pub fn render(&mut self) -> Result<()> {
@@ -132,10 +325,74 @@ impl Renderer {
                         store: true,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &self.depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: true,
+                    }),
+                    stencil_ops: None,
+                }),
             });
-            
-            // Draw calls would go here
+
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(1, self.texture_pool.bind_group(), &[]);
+
+            let chunk_size_meters = CHUNK_SIZE as f32 * VOXEL_SIZE_METERS;
+            let alignment = self.device.limits().min_uniform_buffer_offset_alignment as u64;
+            let stride = align_to(std::mem::size_of::<ChunkOffsetUniform>() as u64, alignment);
+
+            let meshes: Vec<_> = self.mesh_pool.iter().collect();
+            if !meshes.is_empty() {
+                let mut offsets_data = vec![0u8; stride as usize * meshes.len()];
+                for (i, (pos, _, _, _)) in meshes.iter().enumerate() {
+                    let relative = (
+                        (pos.0 - self.camera_chunk_origin.0) as f32,
+                        (pos.1 - self.camera_chunk_origin.1) as f32,
+                        (pos.2 - self.camera_chunk_origin.2) as f32,
+                    );
+                    let uniform = ChunkOffsetUniform::new([
+                        relative.0 * chunk_size_meters,
+                        relative.1 * chunk_size_meters,
+                        relative.2 * chunk_size_meters,
+                    ]);
+                    let start = i * stride as usize;
+                    offsets_data[start..start + std::mem::size_of::<ChunkOffsetUniform>()]
+                        .copy_from_slice(bytemuck::bytes_of(&uniform));
+                }
+
+                let offsets_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Chunk Offsets Buffer"),
+                    contents: &offsets_data,
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+
+                let frame_bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Frame Bind Group"),
+                    layout: &self.camera_bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: self.camera_buffer.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                                buffer: &offsets_buffer,
+                                offset: 0,
+                                size: wgpu::BufferSize::new(std::mem::size_of::<ChunkOffsetUniform>() as u64),
+                            }),
+                        },
+                    ],
+                });
+
+                for (i, (_pos, vertex_buffer, index_buffer, index_count)) in meshes.into_iter().enumerate() {
+                    render_pass.set_bind_group(0, &frame_bind_group, &[i as u32 * stride as u32]);
+                    render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+                    render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                    render_pass.draw_indexed(0..index_count, 0, 0..1);
+                }
+            }
         }
         
         // Submit and present
@@ -0,0 +1,133 @@
+// TexturePool module
+// Owns the block texture array sampled by chunk meshes, keyed by voxel-type layer index
+
+use wgpu::{Device, Queue};
+
+/// Owns a `texture_2d_array` of block textures plus the sampler used to read it, bound
+/// alongside the camera at `@group(1)`
+pub struct TexturePool {
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    bind_group: wgpu::BindGroup,
+}
+
+impl TexturePool {
+    pub const TILE_SIZE: u32 = 16;
+
+    /// Builds a texture array from one RGBA8 layer per entry in `layers` (each
+    /// `TILE_SIZE * TILE_SIZE * 4` bytes). Until a real asset pipeline exists, callers can
+    /// use [`TexturePool::placeholder`] to generate flat-colored layers.
+    pub fn new(device: &Device, queue: &Queue, layers: &[Vec<u8>]) -> Self {
+        let layer_count = layers.len().max(1) as u32;
+        let size = wgpu::Extent3d {
+            width: Self::TILE_SIZE,
+            height: Self::TILE_SIZE,
+            depth_or_array_layers: layer_count,
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Block Texture Array"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        for (layer, pixels) in layers.iter().enumerate() {
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: layer as u32 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                pixels,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * Self::TILE_SIZE),
+                    rows_per_image: Some(Self::TILE_SIZE),
+                },
+                wgpu::Extent3d { width: Self::TILE_SIZE, height: Self::TILE_SIZE, depth_or_array_layers: 1 },
+            );
+        }
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Block Texture Sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            address_mode_w: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Texture Pool Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2Array,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Texture Pool Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+
+        Self {
+            _texture: texture,
+            view,
+            sampler,
+            bind_group_layout,
+            bind_group,
+        }
+    }
+
+    /// Generates one flat-colored, fully-opaque layer per entry in `colors`, for use before a
+    /// real texture-sheet asset pipeline exists
+    pub fn placeholder(device: &Device, queue: &Queue, colors: &[[u8; 4]]) -> Self {
+        let pixel_count = (Self::TILE_SIZE * Self::TILE_SIZE) as usize;
+        let layers: Vec<Vec<u8>> = colors
+            .iter()
+            .map(|color| color.iter().copied().cycle().take(pixel_count * 4).collect())
+            .collect();
+        Self::new(device, queue, &layers)
+    }
+
+    pub fn bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
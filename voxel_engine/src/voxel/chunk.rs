@@ -0,0 +1,111 @@
+// Chunk module
+// CPU-side voxel grid and mesh generation, independent of how it ends up on the GPU
+
+use crate::graphics::Vertex;
+
+pub const CHUNK_SIZE: usize = 32;
+pub const VOXEL_SIZE_METERS: f32 = 0.25;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Voxel {
+    Air,
+    Solid,
+}
+
+impl Voxel {
+    /// Maps a voxel type to its layer in the block texture array; `None` means "don't mesh"
+    pub fn texture_layer(&self) -> Option<u32> {
+        match self {
+            Voxel::Air => None,
+            Voxel::Solid => Some(0),
+        }
+    }
+}
+
+pub struct Chunk {
+    pub voxels: [[[Voxel; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
+}
+
+impl Chunk {
+    pub fn new_filled(voxel: Voxel) -> Self {
+        Self {
+            voxels: [[[voxel; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
+        }
+    }
+
+    /// Emits one quad per visible voxel face; vertex positions are chunk-local (0..CHUNK_SIZE
+    /// in voxel units), ready to be paired with a chunk-origin offset at draw time
+    pub fn to_mesh(&self) -> (Vec<Vertex>, Vec<u32>) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for x in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for z in 0..CHUNK_SIZE {
+                    if self.voxels[x][y][z] != Voxel::Solid {
+                        continue;
+                    }
+                    for (dx, dy, dz, normal) in [
+                        (0, 0, -1, [0.0, 0.0, -1.0]),
+                        (0, 0, 1, [0.0, 0.0, 1.0]),
+                        (0, -1, 0, [0.0, -1.0, 0.0]),
+                        (0, 1, 0, [0.0, 1.0, 0.0]),
+                        (-1, 0, 0, [-1.0, 0.0, 0.0]),
+                        (1, 0, 0, [1.0, 0.0, 0.0]),
+                    ] {
+                        let (nx, ny, nz) = (x as isize + dx, y as isize + dy, z as isize + dz);
+                        let neighbor_solid = nx >= 0 && ny >= 0 && nz >= 0
+                            && (nx as usize) < CHUNK_SIZE && (ny as usize) < CHUNK_SIZE && (nz as usize) < CHUNK_SIZE
+                            && self.voxels[nx as usize][ny as usize][nz as usize] == Voxel::Solid;
+                        if neighbor_solid {
+                            continue;
+                        }
+                        let Some(tex_index) = self.voxels[x][y][z].texture_layer() else {
+                            continue;
+                        };
+                        push_face(&mut vertices, &mut indices, [x as f32, y as f32, z as f32], normal, tex_index);
+                    }
+                }
+            }
+        }
+
+        (vertices, indices)
+    }
+
+    /// Single top quad per (x, z) column, for distant LOD chunks
+    pub fn to_lod_mesh(&self) -> (Vec<Vertex>, Vec<u32>) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                if let Some(y) = (0..CHUNK_SIZE).rev().find(|&y| self.voxels[x][y][z] == Voxel::Solid) {
+                    let tex_index = self.voxels[x][y][z].texture_layer().unwrap_or(0);
+                    push_face(&mut vertices, &mut indices, [x as f32, y as f32, z as f32], [0.0, 1.0, 0.0], tex_index);
+                }
+            }
+        }
+
+        (vertices, indices)
+    }
+}
+
+fn push_face(vertices: &mut Vec<Vertex>, indices: &mut Vec<u32>, base: [f32; 3], normal: [f32; 3], tex_index: u32) {
+    let i = vertices.len() as u32;
+    let [bx, by, bz] = base;
+    let corners: [[f32; 3]; 4] = match normal {
+        [0.0, 0.0, -1.0] => [[bx, by, bz], [bx + 1.0, by, bz], [bx + 1.0, by + 1.0, bz], [bx, by + 1.0, bz]],
+        [0.0, 0.0, 1.0] => [[bx, by, bz + 1.0], [bx, by + 1.0, bz + 1.0], [bx + 1.0, by + 1.0, bz + 1.0], [bx + 1.0, by, bz + 1.0]],
+        [0.0, -1.0, 0.0] => [[bx, by, bz], [bx, by, bz + 1.0], [bx + 1.0, by, bz + 1.0], [bx + 1.0, by, bz]],
+        [0.0, 1.0, 0.0] => [[bx, by + 1.0, bz], [bx + 1.0, by + 1.0, bz], [bx + 1.0, by + 1.0, bz + 1.0], [bx, by + 1.0, bz + 1.0]],
+        [-1.0, 0.0, 0.0] => [[bx, by, bz], [bx, by + 1.0, bz], [bx, by + 1.0, bz + 1.0], [bx, by, bz + 1.0]],
+        [1.0, 0.0, 0.0] => [[bx + 1.0, by, bz], [bx + 1.0, by, bz + 1.0], [bx + 1.0, by + 1.0, bz + 1.0], [bx + 1.0, by + 1.0, bz]],
+        _ => unreachable!(),
+    };
+    // V flipped: wgpu's uv origin is top-left, opposite of the atlas layout these correspond to
+    let uvs = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+    for (corner, uv) in corners.iter().zip(uvs.iter()) {
+        vertices.push(Vertex { position: *corner, normal, uv: *uv, tex_index });
+    }
+    indices.extend_from_slice(&[i, i + 1, i + 2, i, i + 2, i + 3]);
+}
@@ -0,0 +1,100 @@
+// Chunk loading module
+// Keeps `ChunkManager` in sync with the camera position and the renderer's `MeshPool`
+
+use wgpu::Device;
+
+use crate::graphics::MeshPool;
+use super::chunk::{Chunk, Voxel, CHUNK_SIZE};
+use super::manager::{ChunkManager, ChunkState, ManagedChunk};
+
+const ACTIVE_RADIUS: f32 = 2.5;
+const UNLOAD_RADIUS: f32 = 8.0;
+
+/// Generates a flat placeholder chunk (solid below the midpoint) until a real worldgen
+/// pipeline exists for this engine
+fn generate_placeholder_chunk() -> Chunk {
+    let mut chunk = Chunk::new_filled(Voxel::Air);
+    for x in 0..CHUNK_SIZE {
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE / 2 {
+                chunk.voxels[x][y][z] = Voxel::Solid;
+            }
+        }
+    }
+    chunk
+}
+
+/// Loads/unloads chunks around `camera_chunk_pos` and keeps each `ManagedChunk`'s GPU mesh
+/// in sync with its state, uploading into `mesh_pool` on transition to `Active`/`LOD` and
+/// freeing GPU buffers when a chunk drops out of range
+pub fn manage_chunk_loading(
+    chunk_manager: &mut ChunkManager,
+    camera_chunk_pos: (i64, i64, i64),
+    device: &Device,
+    mesh_pool: &mut MeshPool,
+) {
+    chunk_manager.camera_chunk_origin = camera_chunk_pos;
+
+    let r = chunk_manager.radius as i64;
+
+    let mut chunks_to_remove = Vec::new();
+    for (pos, _) in chunk_manager.loaded_chunks.iter() {
+        let dist = chunk_distance(*pos, camera_chunk_pos);
+        if dist > UNLOAD_RADIUS {
+            chunks_to_remove.push(*pos);
+        }
+    }
+    for pos in chunks_to_remove {
+        chunk_manager.loaded_chunks.remove(&pos);
+        mesh_pool.remove(pos);
+    }
+
+    for dx in -r..=r {
+        for dy in -r..=r {
+            for dz in -r..=r {
+                let pos = (camera_chunk_pos.0 + dx, camera_chunk_pos.1 + dy, camera_chunk_pos.2 + dz);
+                if chunk_manager.loaded_chunks.contains_key(&pos) {
+                    continue;
+                }
+                let dist = chunk_distance(pos, camera_chunk_pos);
+                if dist > r as f32 {
+                    continue;
+                }
+
+                let state = if dist <= ACTIVE_RADIUS { ChunkState::Active } else { ChunkState::LOD };
+                let chunk_data = generate_placeholder_chunk();
+
+                let mut managed_chunk = ManagedChunk {
+                    pos,
+                    state,
+                    chunk: None,
+                    lod_mesh: None,
+                    entity: None,
+                };
+
+                match state {
+                    ChunkState::Active => {
+                        let (vertices, indices) = chunk_data.to_mesh();
+                        managed_chunk.entity = Some(mesh_pool.upload(device, pos, &vertices, &indices));
+                        managed_chunk.chunk = Some(chunk_data);
+                    }
+                    ChunkState::LOD => {
+                        let (vertices, indices) = chunk_data.to_lod_mesh();
+                        managed_chunk.entity = Some(mesh_pool.upload(device, pos, &vertices, &indices));
+                        managed_chunk.lod_mesh = Some((vertices, indices));
+                    }
+                    ChunkState::Unloaded => {}
+                }
+
+                chunk_manager.loaded_chunks.insert(pos, managed_chunk);
+            }
+        }
+    }
+}
+
+fn chunk_distance(pos: (i64, i64, i64), camera_chunk_pos: (i64, i64, i64)) -> f32 {
+    let dx = (pos.0 - camera_chunk_pos.0) as f32;
+    let dy = (pos.1 - camera_chunk_pos.1) as f32;
+    let dz = (pos.2 - camera_chunk_pos.2) as f32;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
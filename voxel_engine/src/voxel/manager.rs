@@ -0,0 +1,42 @@
+// ChunkManager module
+// Tracks which chunks are loaded and at what level of detail
+
+use std::collections::HashMap;
+
+use crate::graphics::MeshHandle;
+use super::chunk::Chunk;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkState {
+    Active,   // High detail, full mesh
+    LOD,      // Low detail, heightmap mesh
+    Unloaded, // Not loaded/generated
+}
+
+pub struct ManagedChunk {
+    pub pos: (i64, i64, i64),
+    pub state: ChunkState,
+    pub chunk: Option<Chunk>,       // Only present if Active
+    pub lod_mesh: Option<(Vec<crate::graphics::Vertex>, Vec<u32>)>, // Only present if LOD
+    pub entity: Option<MeshHandle>, // GPU mesh handle for the uploaded mesh (Active or LOD)
+}
+
+/// Tracks which chunks are resident and at what level of detail, mirroring the CPU side of
+/// the renderer's `MeshPool`
+pub struct ChunkManager {
+    pub loaded_chunks: HashMap<(i64, i64, i64), ManagedChunk>,
+    pub radius: i32,
+    /// The chunk the camera currently occupies; draw offsets are computed relative to this
+    /// so GPU-side floats stay small no matter how far the camera has travelled
+    pub camera_chunk_origin: (i64, i64, i64),
+}
+
+impl ChunkManager {
+    pub fn new(radius: i32) -> Self {
+        Self {
+            loaded_chunks: HashMap::new(),
+            radius,
+            camera_chunk_origin: (0, 0, 0),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// Voxel module
+// CPU-side chunk data and the streaming logic that keeps it in sync with the renderer
+
+mod chunk;
+mod chunk_loading;
+mod manager;
+
+pub use chunk::{Chunk, Voxel, CHUNK_SIZE, VOXEL_SIZE_METERS};
+pub use chunk_loading::manage_chunk_loading;
+pub use manager::{ChunkManager, ChunkState, ManagedChunk};
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::terrain::{ATLAS_COLUMNS, ATLAS_ROWS};
+
+/// The voxel texture sheet and its grid layout, loaded once at startup. `Chunk::to_mesh` doesn't
+/// read this directly (it runs off the main thread and has no asset access, see mesh_builder.rs);
+/// it picks atlas tiles from the matching `ATLAS_COLUMNS`/`ATLAS_ROWS` constants instead, and this
+/// resource supplies the actual texture `setup_chunk_material` samples with those UVs.
+#[derive(Resource)]
+pub struct VoxelAtlas {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+}
+
+pub fn setup_voxel_atlas(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let image = asset_server.load("textures/voxel_atlas.png");
+    let layout = layouts.add(TextureAtlasLayout::from_grid(
+        UVec2::splat(16),
+        ATLAS_COLUMNS,
+        ATLAS_ROWS,
+        None,
+        None,
+    ));
+    commands.insert_resource(VoxelAtlas { image, layout });
+}
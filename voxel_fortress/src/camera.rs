@@ -0,0 +1,209 @@
+use bevy::math::EulerRot;
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, PrimaryWindow, WindowFocused};
+
+use crate::input::{ActionState, AxisAction, ButtonAction};
+use crate::player::PlayerController;
+
+const fn to_radians_const(deg: f32) -> f32 {
+    deg * (std::f32::consts::PI / 180.0)
+}
+
+/// Highest pitch magnitude allowed, in radians, before the camera would flip past vertical
+const MAX_PITCH: f32 = to_radians_const(89.0);
+
+/// Bounds the mouse wheel can adjust `CameraController::distance_to_target`/`PlayerController::fly_speed` within
+const MIN_ORBIT_DISTANCE: f32 = 2.0;
+const MAX_ORBIT_DISTANCE: f32 = 100.0;
+const MIN_FLY_SPEED: f32 = 0.5;
+const MAX_FLY_SPEED: f32 = 50.0;
+const ZOOM_SPEED: f32 = 1.0;
+
+/// Meters/second `camera_orbit_pan` moves `CameraController::focus` at while orbiting
+const ORBIT_PAN_SPEED: f32 = 5.0;
+
+/// Whether the camera flies freely under its own rotation, or orbits a fixed `focus` point at
+/// `distance_to_target`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CameraMode {
+    #[default]
+    FreeFly,
+    Orbit,
+}
+
+/// Accumulated look orientation for a captured-cursor camera, rebuilt into a `Quat` every frame
+/// rather than mutated incrementally, so floating-point error never accumulates into drift or an
+/// unintended roll. Also carries the orbit-mode state, since both modes share the same yaw/pitch
+/// look input - only how it's applied to `Transform` differs.
+#[derive(Component)]
+pub struct CameraController {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub captured: bool,
+    pub mode: CameraMode,
+    /// Orbit-mode pivot; WASD pans this instead of the camera directly while orbiting
+    pub focus: Vec3,
+    /// Orbit-mode camera distance from `focus`, adjusted by the mouse wheel
+    pub distance_to_target: f32,
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            captured: false,
+            mode: CameraMode::FreeFly,
+            focus: Vec3::ZERO,
+            distance_to_target: 10.0,
+        }
+    }
+}
+
+/// Toggles cursor capture on `ButtonAction::ToggleCursorCapture`: while captured, the cursor is
+/// locked to the window and hidden, and `camera_look` drives look rotation continuously instead of
+/// only while a button is held.
+pub fn toggle_cursor_capture(
+    action_state: Res<ActionState>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut controllers: Query<&mut CameraController>,
+) {
+    if !action_state.just_pressed(ButtonAction::ToggleCursorCapture) {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    let capturing = window.cursor_options.grab_mode != CursorGrabMode::Locked;
+    set_cursor_captured(&mut window, capturing);
+    for mut controller in controllers.iter_mut() {
+        controller.captured = capturing;
+    }
+}
+
+/// Releases the cursor (and marks every `CameraController` as not captured) when the primary window
+/// loses focus, so players aren't left with a locked, invisible cursor over another application.
+pub fn release_cursor_on_focus_loss(
+    mut focus_events: EventReader<WindowFocused>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut controllers: Query<&mut CameraController>,
+) {
+    if !focus_events.read().any(|event| !event.focused) {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    if window.cursor_options.grab_mode == CursorGrabMode::Locked {
+        set_cursor_captured(&mut window, false);
+        for mut controller in controllers.iter_mut() {
+            controller.captured = false;
+        }
+    }
+}
+
+fn set_cursor_captured(window: &mut Window, captured: bool) {
+    window.cursor_options.grab_mode = if captured { CursorGrabMode::Locked } else { CursorGrabMode::None };
+    window.cursor_options.visible = !captured;
+}
+
+/// Drives look rotation continuously while the cursor is captured, replacing the old
+/// button-held `camera_mouse_look`. Reads `AxisAction::LookYaw`/`LookPitch` like every other
+/// system instead of raw mouse motion, so sensitivity and rebinding stay in one place
+/// (`input::ActionHandler`).
+pub fn camera_look(
+    action_state: Res<ActionState>,
+    mut query: Query<(&mut Transform, &mut CameraController)>,
+) {
+    let yaw = action_state.axis(AxisAction::LookYaw);
+    let pitch = action_state.axis(AxisAction::LookPitch);
+
+    for (mut transform, mut controller) in query.iter_mut() {
+        if !controller.captured || (yaw == 0.0 && pitch == 0.0) {
+            continue;
+        }
+        controller.yaw += yaw;
+        controller.pitch = (controller.pitch + pitch).clamp(-MAX_PITCH, MAX_PITCH);
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, controller.yaw, controller.pitch, 0.0);
+        if controller.mode == CameraMode::Orbit {
+            transform.translation = controller.focus - transform.forward() * controller.distance_to_target;
+        }
+    }
+}
+
+/// Toggles between free-fly and orbit on `ButtonAction::ToggleOrbitMode`. Entering orbit picks the
+/// focus point `distance_to_target` meters ahead of the camera's current facing, so the view
+/// doesn't jump when the mode switches.
+pub fn toggle_camera_mode(
+    action_state: Res<ActionState>,
+    mut query: Query<(&Transform, &mut CameraController)>,
+) {
+    if !action_state.just_pressed(ButtonAction::ToggleOrbitMode) {
+        return;
+    }
+    for (transform, mut controller) in query.iter_mut() {
+        controller.mode = match controller.mode {
+            CameraMode::FreeFly => {
+                controller.focus = transform.translation + transform.forward() * controller.distance_to_target;
+                CameraMode::Orbit
+            }
+            CameraMode::Orbit => CameraMode::FreeFly,
+        };
+    }
+}
+
+/// Mouse wheel zoom: in free-fly mode adjusts `PlayerController::fly_speed`, in orbit mode adjusts
+/// `CameraController::distance_to_target`, both clamped to sane bounds.
+pub fn camera_zoom(
+    action_state: Res<ActionState>,
+    mut query: Query<(&mut PlayerController, &mut CameraController)>,
+) {
+    let scroll = action_state.axis(AxisAction::Zoom);
+    if scroll == 0.0 {
+        return;
+    }
+    for (mut player_controller, mut camera_controller) in query.iter_mut() {
+        match camera_controller.mode {
+            CameraMode::FreeFly => {
+                player_controller.fly_speed =
+                    (player_controller.fly_speed + scroll * ZOOM_SPEED).clamp(MIN_FLY_SPEED, MAX_FLY_SPEED);
+            }
+            CameraMode::Orbit => {
+                camera_controller.distance_to_target = (camera_controller.distance_to_target
+                    - scroll * ZOOM_SPEED)
+                    .clamp(MIN_ORBIT_DISTANCE, MAX_ORBIT_DISTANCE);
+            }
+        }
+    }
+}
+
+/// While orbiting, WASD pans `CameraController::focus` instead of moving the camera directly
+/// (`player::player_movement_system` skips its own translation for the same entity in this mode),
+/// then repositions the camera to keep orbiting `focus` at `distance_to_target`.
+pub fn camera_orbit_pan(
+    action_state: Res<ActionState>,
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &mut CameraController)>,
+) {
+    let move_input = Vec3::new(
+        action_state.axis(AxisAction::MoveStrafe),
+        action_state.axis(AxisAction::MoveVertical),
+        action_state.axis(AxisAction::MoveForwardBackward),
+    );
+
+    for (mut transform, mut controller) in query.iter_mut() {
+        if controller.mode != CameraMode::Orbit {
+            continue;
+        }
+        if move_input.length_squared() > 0.0 {
+            let forward = transform.forward();
+            let right = transform.right();
+            let mut move_vec = Vec3::ZERO;
+            move_vec += forward * -move_input.z;
+            move_vec += right * move_input.x;
+            move_vec += Vec3::Y * move_input.y;
+            controller.focus += move_vec.normalize() * time.delta_secs() * ORBIT_PAN_SPEED;
+        }
+        transform.translation = controller.focus - transform.forward() * controller.distance_to_target;
+    }
+}
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
 use bevy::render::mesh::{Mesh, PrimitiveTopology};
 use bevy::render::render_asset::RenderAssetUsages;
+use crate::config::GameConfig;
 use crate::terrain::{CHUNK_SIZE, VOXEL_SIZE_METERS, Chunk, WorldGen};
 use std::collections::HashMap;
 
-// Reduced radii to improve performance
+// Defaults backing `GameConfig`; a user-editable config file can override these at startup.
 pub const ACTIVE_RADIUS: f32 = 2.5; // Full mesh within this radius
 pub const LOD_RADIUS: f32 = 5.0;    // LOD mesh within this radius, else Unloaded
 pub const UNLOAD_RADIUS: f32 = 8.0; // Beyond this, chunks are fully unloaded from memory
@@ -19,9 +20,15 @@ pub enum ChunkState {
 pub struct ManagedChunk {
     pub pos: (i64, i64, i64),
     pub state: ChunkState,
-    pub chunk: Option<Chunk>, // Only present if Active
+    pub chunk: Option<Chunk>, // Present while Active, and transiently while a LOD mesh build is in flight
     pub lod_mesh: Option<Handle<Mesh>>, // Only present if LOD
+    /// Full-resolution mesh built by `ChunkMeshBuilder`'s worker pool once `state` is `Active`;
+    /// `update_chunk_entities_system` spawns the entity once this is set
+    pub built_mesh: Option<Handle<Mesh>>,
     pub entity: Option<Entity>, // Entity for mesh (Active or LOD)
+    /// Set whenever `chunk` is (re)generated or cleared; `pathfinding::sync_nav_graph` rebuilds or
+    /// invalidates this chunk's contribution to `NavGraph` and clears the flag
+    pub nav_dirty: bool,
 }
 
 #[derive(Resource)]
@@ -44,13 +51,17 @@ impl ChunkManager {
                 for dz in -r..=r {
                     let pos = (center.0 + dx, center.1 + dy, center.2 + dz);
                     if !self.loaded_chunks.contains_key(&pos) {
-                        let chunk = Chunk::from_worldgen(worldgen, pos);
+                        let mut chunk = Chunk::from_worldgen(worldgen, pos);
+                        chunk.apply_mudflow();
+                        worldgen.decorate_chunk(&mut chunk, pos);
                         self.loaded_chunks.insert(pos, ManagedChunk {
                             pos,
                             state: ChunkState::Active,
                             chunk: Some(chunk),
                             lod_mesh: None,
+                            built_mesh: None,
                             entity: None,
+                            nav_dirty: true,
                         });
                     }
                 }
@@ -59,81 +70,143 @@ impl ChunkManager {
     }
 }
 
+/// World-space center of the chunk at `pos`, for distance checks against the camera
+fn chunk_center(pos: (i64, i64, i64)) -> Vec3 {
+    Vec3::new(
+        pos.0 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
+        pos.1 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
+        pos.2 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
+    )
+}
+
+/// Normalized distance (in chunks) from `camera_pos` to the chunk at `pos`
+fn chunk_distance(camera_pos: Vec3, pos: (i64, i64, i64)) -> f32 {
+    camera_pos.distance(chunk_center(pos)) / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)
+}
+
+/// Streams the world in around the camera: each frame, generates `ManagedChunk`s for any position
+/// within `chunk_manager.radius` chunks of the camera that isn't loaded yet, and evicts (despawning
+/// its entity, if any) anything that has drifted beyond `GameConfig::unload_radius`. Continues from
+/// the one-shot preload around the origin that `setup` still does, so the world keeps loading as the
+/// camera moves instead of staying fixed around the origin.
+pub fn stream_chunks_around_camera(
+    mut commands: Commands,
+    mut chunk_manager: ResMut<ChunkManager>,
+    worldgen: Res<WorldGen>,
+    config: Res<GameConfig>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+) {
+    let Some(camera_transform) = camera_query.iter().next() else {
+        return;
+    };
+    let camera_pos = camera_transform.translation;
+    let camera_chunk = (
+        (camera_pos.x / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64,
+        (camera_pos.y / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64,
+        (camera_pos.z / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64,
+    );
+
+    let r = chunk_manager.radius as i64;
+    for dx in -r..=r {
+        for dy in -r..=r {
+            for dz in -r..=r {
+                let pos = (camera_chunk.0 + dx, camera_chunk.1 + dy, camera_chunk.2 + dz);
+                if chunk_manager.loaded_chunks.contains_key(&pos) {
+                    continue;
+                }
+                let mut chunk = Chunk::from_worldgen(&worldgen, pos);
+                chunk.apply_mudflow();
+                worldgen.decorate_chunk(&mut chunk, pos);
+                chunk_manager.loaded_chunks.insert(pos, ManagedChunk {
+                    pos,
+                    state: ChunkState::Active,
+                    chunk: Some(chunk),
+                    lod_mesh: None,
+                    built_mesh: None,
+                    entity: None,
+                    nav_dirty: true,
+                });
+            }
+        }
+    }
+
+    let to_evict: Vec<(i64, i64, i64)> = chunk_manager.loaded_chunks.keys()
+        .filter(|&&pos| chunk_distance(camera_pos, pos) > config.unload_radius)
+        .copied()
+        .collect();
+    for pos in to_evict {
+        if let Some(managed_chunk) = chunk_manager.loaded_chunks.remove(&pos) {
+            if let Some(entity) = managed_chunk.entity {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
 pub fn update_chunk_lod_system(
     mut chunk_manager: ResMut<ChunkManager>,
+    worldgen: Res<WorldGen>,
+    config: Res<GameConfig>,
     camera_query: Query<&Transform, With<Camera3d>>,
-    mut meshes: ResMut<Assets<Mesh>>,
 ) {
     let camera_pos = if let Some(transform) = camera_query.iter().next() {
         transform.translation
     } else {
         return;
     };
-    
-    // Get camera chunk position for loading new chunks
-    let camera_chunk_x = (camera_pos.x / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64;
-    let camera_chunk_y = (camera_pos.y / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64;
-    let camera_chunk_z = (camera_pos.z / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS)).floor() as i64;
-    
+
     for (pos, managed_chunk) in chunk_manager.loaded_chunks.iter_mut() {
-        let chunk_center = Vec3::new(
-            pos.0 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
-            pos.1 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
-            pos.2 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS + (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS) / 2.0,
-        );
-        
-        // Calculate normalized distance (in chunks)
-        let dist = camera_pos.distance(chunk_center) / (CHUNK_SIZE as f32 * VOXEL_SIZE_METERS);
-        let prev_state = managed_chunk.state;
-        
-        // Update chunk state based on distance
-        if dist <= ACTIVE_RADIUS {
+        let dist = chunk_distance(camera_pos, *pos);
+
+        // Update chunk state based on distance. Mesh building itself is offloaded to
+        // `ChunkMeshBuilder`'s worker pool (see mesh_builder.rs): this system only ever
+        // regenerates voxel data and clears whichever mesh handle is now stale, so
+        // `dispatch_chunk_mesh_builds` picks up the rebuild on its next pass.
+        if dist <= config.active_radius {
             // Should be in ACTIVE state
             if managed_chunk.state != ChunkState::Active {
                 // If transitioning from LOD to Active, we need to regenerate the full chunk
                 if managed_chunk.state == ChunkState::LOD {
                     // Generate the full chunk data when transitioning from LOD to Active
-                    let chunk_data = Chunk::from_worldgen(
-                        &WorldGen::new(3, CHUNK_SIZE, 42), // Use the same seed
-                        *pos
-                    );
+                    let mut chunk_data = Chunk::from_worldgen(&worldgen, *pos);
+                    chunk_data.apply_mudflow();
+                    worldgen.decorate_chunk(&mut chunk_data, *pos);
                     managed_chunk.chunk = Some(chunk_data);
                     managed_chunk.lod_mesh = None;
+                    managed_chunk.built_mesh = None;
+                    managed_chunk.nav_dirty = true;
                 }
                 managed_chunk.state = ChunkState::Active;
             }
-        } else if dist <= LOD_RADIUS {
+        } else if dist <= config.lod_radius {
             // Should be in LOD state
             if managed_chunk.state != ChunkState::LOD {
-                if managed_chunk.state == ChunkState::Active {
-                    // Transitioning from Active to LOD
-                    if let Some(chunk) = &managed_chunk.chunk {
-                        let lod_mesh = chunk.to_lod_mesh();
-                        managed_chunk.lod_mesh = Some(meshes.add(lod_mesh));
-                        // Free up memory by removing the full chunk data
-                        managed_chunk.chunk = None;
-                    }
-                } else {
-                    // Transitioning from Unloaded to LOD
-                    // Generate a temporary chunk to create the LOD mesh
-                    let temp_chunk = Chunk::from_worldgen(
-                        &WorldGen::new(3, CHUNK_SIZE, 42),
-                        *pos
-                    );
-                    let lod_mesh = temp_chunk.to_lod_mesh();
-                    managed_chunk.lod_mesh = Some(meshes.add(lod_mesh));
+                if managed_chunk.state != ChunkState::Active {
+                    // Transitioning from Unloaded to LOD: generate the chunk data the LOD mesh
+                    // build will need. Kept on the chunk (rather than discarded) until the build
+                    // completes; `collect_chunk_mesh_builds` clears it once the mesh is ready.
+                    let mut temp_chunk = Chunk::from_worldgen(&worldgen, *pos);
+                    temp_chunk.apply_mudflow();
+                    worldgen.decorate_chunk(&mut temp_chunk, *pos);
+                    managed_chunk.chunk = Some(temp_chunk);
+                    managed_chunk.nav_dirty = true;
                 }
+                // Transitioning from Active to LOD keeps `managed_chunk.chunk` as-is so the
+                // worker pool has voxel data to build the LOD mesh from
+                managed_chunk.lod_mesh = None;
+                managed_chunk.built_mesh = None;
                 managed_chunk.state = ChunkState::LOD;
             }
-        } else if dist <= UNLOAD_RADIUS {
+        } else if dist <= config.unload_radius {
             // Keep in memory but no visuals
             if managed_chunk.state != ChunkState::Unloaded {
                 managed_chunk.chunk = None;
                 managed_chunk.lod_mesh = None;
+                managed_chunk.built_mesh = None;
+                managed_chunk.nav_dirty = true;
                 managed_chunk.state = ChunkState::Unloaded;
             }
         }
-        // Beyond UNLOAD_RADIUS chunks will be removed from memory entirely
-        // (This is handled elsewhere)
+        // Beyond config.unload_radius chunks are evicted entirely by `stream_chunks_around_camera`
     }
 }
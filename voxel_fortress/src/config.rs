@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::chunk::{ACTIVE_RADIUS, LOD_RADIUS, UNLOAD_RADIUS};
+
+/// Path (relative to the working directory) of the user-editable settings file `load_game_config`
+/// looks for at startup
+const CONFIG_PATH: &str = "config/game.json5";
+
+/// World/render tuning values, loaded once at startup from `CONFIG_PATH` and falling back to the
+/// hardcoded defaults below if the file is missing or fails to parse. Systems read these instead of
+/// the compile-time constants they used to, so players can retune draw distance, world seed, and
+/// movement feel by editing the file instead of recompiling.
+#[derive(Resource, Clone, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub active_radius: f32,
+    pub lod_radius: f32,
+    pub unload_radius: f32,
+    pub chunk_load_radius: i32,
+    pub world_seed: u64,
+    pub world_octaves: u8,
+    pub camera_rotate_speed: f32,
+    pub mouse_sensitivity: f32,
+    /// Not read by any system yet; reserved for a future frame-pacing pass.
+    pub target_fps: f32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            active_radius: ACTIVE_RADIUS,
+            lod_radius: LOD_RADIUS,
+            unload_radius: UNLOAD_RADIUS,
+            chunk_load_radius: 5,
+            world_seed: 42,
+            world_octaves: 3,
+            camera_rotate_speed: 0.05,
+            mouse_sensitivity: 0.002,
+            target_fps: 60.0,
+        }
+    }
+}
+
+/// Loads `GameConfig` from `CONFIG_PATH` (json5, so the file can carry comments and trailing
+/// commas), falling back to [`GameConfig::default`] if the file is missing or fails to parse. Must
+/// run before any system that reads `GameConfig`.
+pub fn load_game_config(mut commands: Commands) {
+    let config = std::fs::read_to_string(CONFIG_PATH)
+        .ok()
+        .and_then(|contents| json5::from_str::<GameConfig>(&contents).ok())
+        .unwrap_or_default();
+    commands.insert_resource(config);
+}
@@ -0,0 +1,180 @@
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// A continuously-valued control resolved each frame from opposing key pairs, mouse motion, or the
+/// mouse wheel
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AxisAction {
+    MoveForwardBackward,
+    MoveStrafe,
+    MoveVertical,
+    RotateYaw,
+    RotatePitch,
+    LookYaw,
+    LookPitch,
+    Zoom,
+}
+
+/// A discrete control whose pressed/just-pressed state is queried directly
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ButtonAction {
+    ToggleCursorCapture,
+    ToggleOrbitMode,
+    Jump,
+    ToggleFly,
+    CycleSkybox,
+}
+
+/// One physical input contributing to an axis action. Several bindings can feed the same axis
+/// (e.g. a key pair and mouse motion); their values sum.
+#[derive(Clone, Copy, Debug)]
+pub enum AxisBinding {
+    KeyPair { positive: KeyCode, negative: KeyCode },
+    MouseMotionX(f32), // sensitivity, negative to invert
+    MouseMotionY(f32),
+    MouseWheelY(f32), // sensitivity, negative to invert
+}
+
+/// One physical input contributing to a button action
+#[derive(Clone, Copy, Debug)]
+pub enum ButtonBinding {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+}
+
+/// Maps named actions to physical inputs, so the movement/look systems never see a raw `KeyCode`.
+/// Built from [`ActionHandler::default_bindings`] at startup; no binding set is loaded from the
+/// config file, and the bindings cannot be replaced after construction.
+#[derive(Resource, Clone)]
+pub struct ActionHandler {
+    axis_bindings: HashMap<AxisAction, Vec<AxisBinding>>,
+    button_bindings: HashMap<ButtonAction, Vec<ButtonBinding>>,
+}
+
+impl ActionHandler {
+    /// `mouse_sensitivity` is the look-speed magnitude from `GameConfig`; both look axes invert it,
+    /// matching the feel of the old hardcoded `-0.002` bindings.
+    pub fn default_bindings(mouse_sensitivity: f32) -> Self {
+        let mut axis_bindings = HashMap::new();
+        axis_bindings.insert(
+            AxisAction::MoveForwardBackward,
+            vec![AxisBinding::KeyPair { positive: KeyCode::KeyS, negative: KeyCode::KeyW }],
+        );
+        axis_bindings.insert(
+            AxisAction::MoveStrafe,
+            vec![AxisBinding::KeyPair { positive: KeyCode::KeyD, negative: KeyCode::KeyA }],
+        );
+        axis_bindings.insert(
+            AxisAction::MoveVertical,
+            vec![AxisBinding::KeyPair { positive: KeyCode::KeyE, negative: KeyCode::KeyQ }],
+        );
+        axis_bindings.insert(
+            AxisAction::RotateYaw,
+            vec![AxisBinding::KeyPair { positive: KeyCode::ArrowLeft, negative: KeyCode::ArrowRight }],
+        );
+        axis_bindings.insert(
+            AxisAction::RotatePitch,
+            vec![AxisBinding::KeyPair { positive: KeyCode::ArrowUp, negative: KeyCode::ArrowDown }],
+        );
+        axis_bindings.insert(AxisAction::LookYaw, vec![AxisBinding::MouseMotionX(-mouse_sensitivity)]);
+        axis_bindings.insert(AxisAction::LookPitch, vec![AxisBinding::MouseMotionY(-mouse_sensitivity)]);
+        axis_bindings.insert(AxisAction::Zoom, vec![AxisBinding::MouseWheelY(1.0)]);
+
+        let mut button_bindings = HashMap::new();
+        button_bindings.insert(ButtonAction::ToggleCursorCapture, vec![ButtonBinding::Key(KeyCode::Tab)]);
+        button_bindings.insert(ButtonAction::ToggleOrbitMode, vec![ButtonBinding::Key(KeyCode::KeyC)]);
+        button_bindings.insert(ButtonAction::Jump, vec![ButtonBinding::Key(KeyCode::Space)]);
+        button_bindings.insert(ButtonAction::ToggleFly, vec![ButtonBinding::Key(KeyCode::KeyF)]);
+        button_bindings.insert(ButtonAction::CycleSkybox, vec![ButtonBinding::Key(KeyCode::KeyV)]);
+
+        Self { axis_bindings, button_bindings }
+    }
+}
+
+/// Per-frame resolved values for every action, read by `camera::camera_look`/`camera_movement`
+/// instead of raw `KeyCode`s. Populated by [`resolve_actions`], which must run before any system
+/// that reads it.
+#[derive(Resource, Default)]
+pub struct ActionState {
+    axes: HashMap<AxisAction, f32>,
+    pressed: HashMap<ButtonAction, bool>,
+    just_pressed: HashMap<ButtonAction, bool>,
+}
+
+impl ActionState {
+    pub fn axis(&self, action: AxisAction) -> f32 {
+        *self.axes.get(&action).unwrap_or(&0.0)
+    }
+
+    pub fn pressed(&self, action: ButtonAction) -> bool {
+        *self.pressed.get(&action).unwrap_or(&false)
+    }
+
+    pub fn just_pressed(&self, action: ButtonAction) -> bool {
+        *self.just_pressed.get(&action).unwrap_or(&false)
+    }
+}
+
+/// Resolves every bound action against this frame's raw input and stores the results in
+/// `ActionState`. Runs once per frame, before `camera::camera_look`/`camera_movement`, so those
+/// systems never touch a `KeyCode` directly.
+pub fn resolve_actions(
+    action_handler: Res<ActionHandler>,
+    mut action_state: ResMut<ActionState>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+) {
+    let mut mouse_delta = Vec2::ZERO;
+    for event in mouse_motion_events.read() {
+        mouse_delta += event.delta;
+    }
+    let wheel_delta: f32 = mouse_wheel_events.read().map(|event| event.y).sum();
+
+    action_state.axes.clear();
+    for (&action, bindings) in action_handler.axis_bindings.iter() {
+        let mut value = 0.0;
+        for binding in bindings {
+            value += match *binding {
+                AxisBinding::KeyPair { positive, negative } => {
+                    let mut v = 0.0;
+                    if keys.pressed(positive) {
+                        v += 1.0;
+                    }
+                    if keys.pressed(negative) {
+                        v -= 1.0;
+                    }
+                    v
+                }
+                AxisBinding::MouseMotionX(sensitivity) => mouse_delta.x * sensitivity,
+                AxisBinding::MouseMotionY(sensitivity) => mouse_delta.y * sensitivity,
+                AxisBinding::MouseWheelY(sensitivity) => wheel_delta * sensitivity,
+            };
+        }
+        action_state.axes.insert(action, value);
+    }
+
+    action_state.pressed.clear();
+    action_state.just_pressed.clear();
+    for (&action, bindings) in action_handler.button_bindings.iter() {
+        let mut pressed = false;
+        let mut just_pressed = false;
+        for binding in bindings {
+            match *binding {
+                ButtonBinding::Key(key) => {
+                    pressed |= keys.pressed(key);
+                    just_pressed |= keys.just_pressed(key);
+                }
+                ButtonBinding::MouseButton(button) => {
+                    pressed |= mouse_buttons.pressed(button);
+                    just_pressed |= mouse_buttons.just_pressed(button);
+                }
+            }
+        }
+        action_state.pressed.insert(action, pressed);
+        action_state.just_pressed.insert(action, just_pressed);
+    }
+}
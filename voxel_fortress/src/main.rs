@@ -1,16 +1,30 @@
-use bevy::input::keyboard::KeyCode;
-use bevy::input::ButtonInput;
+use bevy::core_pipeline::bloom::Bloom;
+use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::prelude::*;
-use bevy::render::camera::ClearColor;
 
+mod atlas;
+mod camera;
 mod chunk;
+mod config;
+mod input;
+mod mesh_builder;
+mod pathfinding;
+mod player;
+mod skybox;
 mod systems;
 
+use atlas::VoxelAtlas;
+use camera::CameraController;
 use chunk::*;
+use config::GameConfig;
+use input::{ActionHandler, ActionState, AxisAction};
+use mesh_builder::ChunkMeshBuilder;
+use player::PlayerController;
+use skybox::Cubemap;
 use systems::*;
 
 mod terrain;
-use terrain::{CHUNK_SIZE, VOXEL_SIZE_METERS, Voxel, WorldGen};
+use terrain::{CHUNK_SIZE, VOXEL_SIZE_METERS, WorldGen};
 
 #[derive(Resource, Clone)]
 pub struct ChunkMaterialHandle(pub Handle<StandardMaterial>);
@@ -18,101 +32,82 @@ pub struct ChunkMaterialHandle(pub Handle<StandardMaterial>);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .insert_resource(ClearColor(Color::srgb(0.5, 0.7, 1.0)))
-        .insert_resource(ChunkManager::new(5))
-        .add_systems(Startup, setup_chunk_material)
-        .add_systems(Startup, setup)
-        .add_systems(Update, camera_movement)
-        .add_systems(Update, camera_mouse_look)
+        .insert_resource(ChunkMeshBuilder::new())
+        .init_resource::<ActionState>()
+        .init_resource::<systems::FloatingOrigin>()
+        .init_resource::<systems::RenderQuality>()
+        .init_resource::<systems::PowerMode>()
+        .init_resource::<systems::ChunkStateSnapshot>()
+        .init_resource::<bevy::winit::WinitSettings>()
+        .init_resource::<pathfinding::NavGraph>()
+        .add_event::<pathfinding::PathRequest>()
+        .add_event::<pathfinding::PathResult>()
+        .add_systems(Startup, config::load_game_config)
+        .add_systems(Startup, setup_world_resources.after(config::load_game_config))
+        .add_systems(Startup, atlas::setup_voxel_atlas)
+        .add_systems(Startup, setup_chunk_material.after(atlas::setup_voxel_atlas))
+        .add_systems(Startup, skybox::setup_skybox)
+        .add_systems(Startup, systems::setup_world_lighting)
+        .add_systems(Startup, setup.after(skybox::setup_skybox).after(setup_world_resources))
+        .add_systems(Update, input::resolve_actions)
+        .add_systems(Update, camera_movement.after(input::resolve_actions))
+        .add_systems(Update, camera::toggle_cursor_capture.after(input::resolve_actions))
+        .add_systems(Update, camera::release_cursor_on_focus_loss)
+        .add_systems(
+            Update,
+            camera::camera_look
+                .after(input::resolve_actions)
+                .after(camera::toggle_cursor_capture),
+        )
+        .add_systems(Update, camera::toggle_camera_mode.after(input::resolve_actions))
+        .add_systems(Update, camera::camera_zoom.after(input::resolve_actions))
+        .add_systems(Update, skybox::cycle_skybox.after(input::resolve_actions))
+        .add_systems(Update, skybox::reinterpret_loaded_cubemap)
+        .add_systems(FixedUpdate, player::player_movement_system)
+        .add_systems(FixedUpdate, camera::camera_orbit_pan.after(player::player_movement_system))
         .add_systems(Update, systems::loading_progress_ui)
+        .add_systems(Update, chunk::stream_chunks_around_camera)
         .add_systems(Update, chunk::update_chunk_lod_system)
-        .add_systems(Update, systems::update_chunk_entities_system)
+        .add_systems(Update, mesh_builder::dispatch_chunk_mesh_builds)
+        .add_systems(Update, mesh_builder::collect_chunk_mesh_builds)
+        .add_systems(Update, systems::rebase_floating_origin)
+        .add_systems(Update, systems::update_chunk_entities_system.after(systems::rebase_floating_origin))
+        .add_systems(Update, pathfinding::sync_nav_graph)
+        .add_systems(Update, pathfinding::handle_path_requests.after(pathfinding::sync_nav_graph))
+        .add_systems(Update, systems::update_power_mode)
         .run();
 }
 
-fn camera_mouse_look(
-    mut mouse_motion_events: EventReader<bevy::input::mouse::MouseMotion>,
-    mouse_button_input: Res<ButtonInput<bevy::input::mouse::MouseButton>>,
-    mut query: Query<&mut Transform, With<Camera3d>>,
-) {
-    if !mouse_button_input.pressed(bevy::input::mouse::MouseButton::Right) {
-        return;
-    }
-    let mut delta = Vec2::ZERO;
-    for event in mouse_motion_events.read() {
-        delta += event.delta;
-    }
-    if delta == Vec2::ZERO {
-        return;
-    }
-    for mut transform in query.iter_mut() {
-        // Sensitivity can be adjusted as needed
-        let sensitivity = 0.002;
-        let yaw = -delta.x * sensitivity;
-        let pitch = -delta.y * sensitivity;
-        // Apply yaw (around global Y)
-        transform.rotate(Quat::from_rotation_y(yaw));
-        // Apply pitch (around local X)
-        let right = transform.rotation * Vec3::X;
-        transform.rotate(Quat::from_axis_angle(right, pitch));
-    }
+/// Inserts the resources whose construction depends on `GameConfig` (world seed/octaves, chunk load
+/// radius, input sensitivity). Must run after `config::load_game_config` and before anything that
+/// reads `WorldGen`, `ChunkManager`, or `ActionHandler`.
+fn setup_world_resources(mut commands: Commands, config: Res<GameConfig>) {
+    commands.insert_resource(WorldGen::new(config.world_octaves, CHUNK_SIZE, config.world_seed));
+    commands.insert_resource(ChunkManager::new(config.chunk_load_radius));
+    commands.insert_resource(ActionHandler::default_bindings(config.mouse_sensitivity));
 }
 
+// Translation is handled by `player::player_movement_system` (fly mode and voxel-collision walk
+// mode); this system only applies the arrow-key free-look rotation, which isn't mode-dependent.
+// Skipped while the cursor is captured, since `camera::camera_look` owns `Transform.rotation`
+// exclusively in that mode and would otherwise stomp this system's rotation on the next frame.
 fn camera_movement(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut query: Query<&mut Transform, With<Camera3d>>,
-    time: Res<Time>,
+    action_state: Res<ActionState>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Transform, &CameraController)>,
 ) {
-    for mut transform in query.iter_mut() {
-        let mut direction = Vec3::ZERO;
-
-        // Movement controls
-        if keyboard_input.pressed(KeyCode::KeyW) {
-            direction.z -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyS) {
-            direction.z += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyA) {
-            direction.x -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyD) {
-            direction.x += 1.0;
+    for (mut transform, camera_controller) in query.iter_mut() {
+        if camera_controller.captured {
+            continue;
         }
-        if keyboard_input.pressed(KeyCode::KeyE) {
-            direction.y += 1.0;
+        let rotate_yaw = action_state.axis(AxisAction::RotateYaw) * config.camera_rotate_speed;
+        if rotate_yaw != 0.0 {
+            transform.rotate(Quat::from_rotation_y(rotate_yaw));
         }
-        if keyboard_input.pressed(KeyCode::KeyQ) {
-            direction.y -= 1.0;
-        }
-
-        // Apply movement relative to camera orientation
-        if direction.length_squared() > 0.0 {
-            // Only use X and Z for planar movement
-            let forward = transform.forward();
-            let right = transform.right();
-            let up = Vec3::Y;
-            let mut move_vec = Vec3::ZERO;
-            move_vec += forward * -direction.z; // Invert Z so W is forward
-            move_vec += right * direction.x;
-            move_vec += up * direction.y;
-            transform.translation += move_vec.normalize() * time.delta_secs() * 5.0;
-        }
-
-        // Rotation controls
-        if keyboard_input.pressed(KeyCode::ArrowLeft) {
-            transform.rotate(Quat::from_rotation_y(0.05));
-        }
-        if keyboard_input.pressed(KeyCode::ArrowRight) {
-            transform.rotate(Quat::from_rotation_y(-0.05));
-        }
-        if keyboard_input.pressed(KeyCode::ArrowUp) {
-            let right = transform.rotation * Vec3::X;
-            transform.rotate(Quat::from_axis_angle(right, 0.05));
-        }
-        if keyboard_input.pressed(KeyCode::ArrowDown) {
+        let rotate_pitch = action_state.axis(AxisAction::RotatePitch) * config.camera_rotate_speed;
+        if rotate_pitch != 0.0 {
             let right = transform.rotation * Vec3::X;
-            transform.rotate(Quat::from_axis_angle(right, -0.05));
+            transform.rotate(Quat::from_axis_angle(right, rotate_pitch));
         }
     }
 }
@@ -120,9 +115,10 @@ fn camera_movement(
 fn setup(
     mut commands: Commands,
     mut chunk_manager: ResMut<ChunkManager>,
+    worldgen: Res<WorldGen>,
+    cubemap: Res<Cubemap>,
+    render_quality: Res<systems::RenderQuality>,
 ) {
-    // --- WorldGen instance ---
-    let worldgen = WorldGen::new(3, CHUNK_SIZE, 42);
     chunk_manager.loaded_chunks.clear();
     chunk_manager.load_chunks_around((0, 0, 0), &worldgen);
     // Find the highest ground in the center chunk for player spawn
@@ -132,7 +128,7 @@ fn setup(
         for x in 0..CHUNK_SIZE {
             for z in 0..CHUNK_SIZE {
                 for y in (0..CHUNK_SIZE).rev() {
-                    if chunk.voxels[x][y][z] == Voxel::Solid {
+                    if chunk.voxels[x][y][z].is_solid() {
                         let world_y = y as f32 * VOXEL_SIZE_METERS;
                         if world_y > max_ground_y {
                             max_ground_y = world_y;
@@ -143,11 +139,21 @@ fn setup(
             }
         }
     }
-    // Add a camera above the ground
-    commands.spawn((
+    // Add a camera above the ground. On `RenderQuality::High`, enable HDR and attach the bloom +
+    // tonemapping post-process pass that `systems::setup_world_lighting`'s sun is meant to be seen
+    // through; `Low` leaves the camera as a plain LDR pass for weaker hardware.
+    let hdr = render_quality.hdr_enabled();
+    let mut camera = commands.spawn((
         Camera3d::default(),
+        Camera { hdr, ..default() },
         Transform::from_xyz(0.0, max_ground_y + 3.0, 0.0).looking_at(Vec3::new(0.0, max_ground_y, 0.0), Vec3::Y),
+        PlayerController::default(),
+        CameraController::default(),
+        skybox::skybox_component(&cubemap),
     ));
+    if hdr {
+        camera.insert((Bloom::default(), Tonemapping::TonyMcMapface));
+    }
 
     // --- Loading Progress UI ---
     commands
@@ -166,9 +172,10 @@ fn setup(
 fn setup_chunk_material(
     mut commands: Commands,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    voxel_atlas: Res<VoxelAtlas>,
 ) {
     let chunk_material_handle = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.7, 0.7, 0.7),
+        base_color_texture: Some(voxel_atlas.image.clone()),
         perceptual_roughness: 0.9,
         ..default()
     });
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+use bevy::render::render_asset::RenderAssetUsages;
+
+use crate::chunk::{ChunkManager, ChunkState};
+use crate::terrain::Chunk;
+
+/// How many worker threads `ChunkMeshBuilder` keeps alive for the lifetime of the app
+const WORKER_COUNT: usize = 4;
+
+/// Which mesh variant a worker thread should build for a chunk
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MeshKind {
+    Full,
+    Lod,
+}
+
+/// Work handed to a builder thread: a snapshot of the chunk's voxel data plus which mesh to build
+struct BuildReq {
+    pos: (i64, i64, i64),
+    kind: MeshKind,
+    chunk: Chunk,
+}
+
+/// Raw mesh buffers a worker thread hands back. The worker builds a `Mesh` with `Chunk::to_mesh`/
+/// `to_lod_mesh` and copies its attributes out into plain `Vec`s; the collection system reassembles
+/// a `Mesh` from them and adds it to `Assets<Mesh>` on the main thread.
+struct BuildReply {
+    pos: (i64, i64, i64),
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+/// Owns a fixed pool of worker threads that build chunk meshes off the main thread, so a batch of
+/// chunks flipping to `Active`/`LOD` doesn't stall a frame. Each worker has its own request
+/// channel; all workers share one reply channel. `building` records which chunk and requested
+/// `ChunkState` each in-flight job belongs to, so a reply can be dropped if the chunk unloaded or
+/// changed level while the job was in flight.
+// `Resource` requires `Sync`, which `Sender`/`Receiver` don't provide on their own; the mutexes
+// cost nothing in practice since `dispatch_chunk_mesh_builds`/`collect_chunk_mesh_builds` are the
+// only systems that ever touch this resource, and Bevy already gives them exclusive `&mut` access
+#[derive(Resource)]
+pub struct ChunkMeshBuilder {
+    req_senders: Vec<Mutex<Sender<BuildReq>>>,
+    reply_receiver: Mutex<Receiver<(usize, BuildReply)>>,
+    free_builders: Vec<usize>,
+    building: HashMap<(i64, i64, i64), (usize, ChunkState)>,
+}
+
+impl ChunkMeshBuilder {
+    pub fn new() -> Self {
+        let (reply_tx, reply_rx) = mpsc::channel::<(usize, BuildReply)>();
+        let mut req_senders = Vec::with_capacity(WORKER_COUNT);
+
+        for worker_id in 0..WORKER_COUNT {
+            let (req_tx, req_rx) = mpsc::channel::<BuildReq>();
+            let reply_tx = reply_tx.clone();
+            thread::spawn(move || {
+                while let Ok(req) = req_rx.recv() {
+                    let mesh = match req.kind {
+                        MeshKind::Full => req.chunk.to_mesh(),
+                        MeshKind::Lod => req.chunk.to_lod_mesh(),
+                    };
+                    let reply = BuildReply {
+                        pos: req.pos,
+                        positions: mesh_attribute_vec3(&mesh, Mesh::ATTRIBUTE_POSITION),
+                        normals: mesh_attribute_vec3(&mesh, Mesh::ATTRIBUTE_NORMAL),
+                        colors: mesh_attribute_vec4(&mesh, Mesh::ATTRIBUTE_COLOR),
+                        uvs: mesh_attribute_vec2(&mesh, Mesh::ATTRIBUTE_UV_0),
+                        indices: mesh_indices(&mesh),
+                    };
+                    if reply_tx.send((worker_id, reply)).is_err() {
+                        break;
+                    }
+                }
+            });
+            req_senders.push(req_tx);
+        }
+
+        Self {
+            req_senders: req_senders.into_iter().map(Mutex::new).collect(),
+            reply_receiver: Mutex::new(reply_rx),
+            free_builders: (0..WORKER_COUNT).collect(),
+            building: HashMap::new(),
+        }
+    }
+}
+
+fn mesh_attribute_vec3(mesh: &Mesh, id: bevy::render::mesh::MeshVertexAttribute) -> Vec<[f32; 3]> {
+    match mesh.attribute(id) {
+        Some(VertexAttributeValues::Float32x3(values)) => values.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn mesh_attribute_vec4(mesh: &Mesh, id: bevy::render::mesh::MeshVertexAttribute) -> Vec<[f32; 4]> {
+    match mesh.attribute(id) {
+        Some(VertexAttributeValues::Float32x4(values)) => values.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn mesh_attribute_vec2(mesh: &Mesh, id: bevy::render::mesh::MeshVertexAttribute) -> Vec<[f32; 2]> {
+    match mesh.attribute(id) {
+        Some(VertexAttributeValues::Float32x2(values)) => values.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn mesh_indices(mesh: &Mesh) -> Vec<u32> {
+    match mesh.indices() {
+        Some(Indices::U32(values)) => values.clone(),
+        Some(Indices::U16(values)) => values.iter().map(|&i| i as u32).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn buffers_to_mesh(reply: BuildReply) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, reply.positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, reply.normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, reply.colors);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, reply.uvs);
+    mesh.insert_indices(Indices::U32(reply.indices));
+    mesh
+}
+
+/// Scans `ChunkManager` for chunks that need a mesh built (their state implies one but no entity
+/// exists yet and no mesh is ready), pops a free builder, and sends a snapshot of the chunk's
+/// voxel data off to it. Marks the chunk "building" so it isn't queued again while the job is in
+/// flight.
+pub fn dispatch_chunk_mesh_builds(
+    mut builder: ResMut<ChunkMeshBuilder>,
+    chunk_manager: Res<ChunkManager>,
+) {
+    for (&pos, managed_chunk) in chunk_manager.loaded_chunks.iter() {
+        if builder.free_builders.is_empty() {
+            break;
+        }
+        if managed_chunk.entity.is_some() || builder.building.contains_key(&pos) {
+            continue;
+        }
+
+        let kind = match managed_chunk.state {
+            ChunkState::Active if managed_chunk.built_mesh.is_none() => MeshKind::Full,
+            ChunkState::LOD if managed_chunk.lod_mesh.is_none() => MeshKind::Lod,
+            _ => continue,
+        };
+        let Some(chunk) = managed_chunk.chunk.clone() else {
+            continue;
+        };
+
+        let worker_id = builder.free_builders.pop().unwrap();
+        let req = BuildReq { pos, kind, chunk };
+        if builder.req_senders[worker_id].lock().unwrap().send(req).is_ok() {
+            builder.building.insert(pos, (worker_id, managed_chunk.state));
+        } else {
+            builder.free_builders.push(worker_id);
+        }
+    }
+}
+
+/// Drains whatever mesh build replies have arrived this frame, assembles a real `Mesh` from each
+/// one, and stores its handle on the chunk so `update_chunk_entities_system` can spawn the entity.
+/// Re-checks that the chunk still exists and its state still matches what was requested before
+/// applying a reply, discarding stale replies for chunks that unloaded or changed level while the
+/// job was in flight.
+pub fn collect_chunk_mesh_builds(
+    mut builder: ResMut<ChunkMeshBuilder>,
+    mut chunk_manager: ResMut<ChunkManager>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    while let Ok((worker_id, reply)) = builder.reply_receiver.lock().unwrap().try_recv() {
+        builder.free_builders.push(worker_id);
+
+        let Some((_, requested_state)) = builder.building.remove(&reply.pos) else {
+            continue;
+        };
+        let Some(managed_chunk) = chunk_manager.loaded_chunks.get_mut(&reply.pos) else {
+            continue;
+        };
+        if managed_chunk.state != requested_state {
+            continue;
+        }
+
+        let handle = meshes.add(buffers_to_mesh(reply));
+        match requested_state {
+            ChunkState::Active => managed_chunk.built_mesh = Some(handle),
+            ChunkState::LOD => {
+                managed_chunk.lod_mesh = Some(handle);
+                // Only kept around so this build had voxel data to chew on
+                managed_chunk.chunk = None;
+                managed_chunk.nav_dirty = true;
+            }
+            ChunkState::Unloaded => {}
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::chunk::ChunkManager;
+use crate::terrain::{Chunk, Voxel, CHUNK_SIZE, VOXEL_SIZE_METERS};
+
+/// `Voxel::Air` cells required above a footing cell for something to stand there
+const HEADROOM_VOXELS: usize = 2;
+
+/// Walkable-cell graph built from loaded chunk voxel data. A global voxel position is a node if it
+/// is `Voxel::Air` with solid footing directly beneath it (see `Voxel::is_solid`) and starts a
+/// column of `HEADROOM_VOXELS` `Voxel::Air` cells; edges connect the 8 horizontal neighbors plus single-step up/down
+/// transitions onto adjacent footing. Rebuilt one chunk at a time by `sync_nav_graph`, so only the
+/// chunks whose voxels actually changed are recomputed.
+#[derive(Resource, Default)]
+pub struct NavGraph {
+    walkable: HashSet<IVec3>,
+    by_chunk: HashMap<(i64, i64, i64), Vec<IVec3>>,
+}
+
+impl NavGraph {
+    /// Global voxel coordinate of chunk-local position `(x, y, z)` within the chunk at `pos`,
+    /// matching the chunk/local decomposition `player.rs`'s `voxel_at_world` uses
+    fn global_voxel(pos: (i64, i64, i64), x: usize, y: usize, z: usize) -> IVec3 {
+        IVec3::new(
+            (pos.0 * CHUNK_SIZE as i64 + x as i64) as i32,
+            (pos.1 * CHUNK_SIZE as i64 + y as i64) as i32,
+            (pos.2 * CHUNK_SIZE as i64 + z as i64) as i32,
+        )
+    }
+
+    /// Recomputes the walkable nodes contributed by the chunk at `pos`, replacing whatever it
+    /// previously contributed. Footing/headroom is only checked within this chunk's own bounds (the
+    /// neighboring chunk isn't read here), so a cell at the chunk's bottom edge, or whose headroom
+    /// column would reach past its top edge, is never a node.
+    pub fn rebuild_chunk(&mut self, pos: (i64, i64, i64), chunk: &Chunk) {
+        self.invalidate_chunk(pos);
+        let mut nodes = Vec::new();
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                for y in 1..CHUNK_SIZE {
+                    if chunk.voxels[x][y][z] != Voxel::Air {
+                        continue;
+                    }
+                    if !chunk.voxels[x][y - 1][z].is_solid() {
+                        continue;
+                    }
+                    let has_headroom = (0..HEADROOM_VOXELS)
+                        .all(|h| y + h < CHUNK_SIZE && chunk.voxels[x][y + h][z] == Voxel::Air);
+                    if !has_headroom {
+                        continue;
+                    }
+                    nodes.push(Self::global_voxel(pos, x, y, z));
+                }
+            }
+        }
+        self.walkable.extend(nodes.iter().copied());
+        self.by_chunk.insert(pos, nodes);
+    }
+
+    /// Removes every node this chunk previously contributed, e.g. because it was unloaded
+    pub fn invalidate_chunk(&mut self, pos: (i64, i64, i64)) {
+        if let Some(nodes) = self.by_chunk.remove(&pos) {
+            for node in nodes {
+                self.walkable.remove(&node);
+            }
+        }
+    }
+
+    pub fn is_walkable(&self, node: IVec3) -> bool {
+        self.walkable.contains(&node)
+    }
+
+    /// The 8 horizontal neighbors plus single-step up/down transitions onto them, filtered to
+    /// those that are themselves walkable nodes
+    fn neighbors(&self, node: IVec3) -> Vec<IVec3> {
+        let mut result = Vec::new();
+        for dx in -1..=1i32 {
+            for dz in -1..=1i32 {
+                if dx == 0 && dz == 0 {
+                    continue;
+                }
+                for dy in -1..=1i32 {
+                    let candidate = node + IVec3::new(dx, dy, dz);
+                    if self.is_walkable(candidate) {
+                        result.push(candidate);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Requests a route between two voxel positions; answered by [`handle_path_requests`] emitting a
+/// matching [`PathResult`], possibly on a later frame if `NavGraph` doesn't yet cover both ends.
+#[derive(Event)]
+pub struct PathRequest {
+    pub start: IVec3,
+    pub goal: IVec3,
+}
+
+/// `waypoints` is `None` if `start`/`goal` aren't walkable nodes or no path connects them
+#[derive(Event)]
+pub struct PathResult {
+    pub start: IVec3,
+    pub goal: IVec3,
+    pub waypoints: Option<Vec<IVec3>>,
+}
+
+/// Min-heap entry ordered by ascending `f_score` (`BinaryHeap` is a max-heap, so comparisons are
+/// reversed)
+#[derive(Copy, Clone, PartialEq)]
+struct ScoredNode {
+    node: IVec3,
+    f_score: f32,
+}
+
+impl Eq for ScoredNode {}
+
+impl Ord for ScoredNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_score.partial_cmp(&self.f_score).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for ScoredNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn heuristic(a: IVec3, b: IVec3) -> f32 {
+    VOXEL_SIZE_METERS * (a.as_vec3() - b.as_vec3()).length()
+}
+
+fn reconstruct_path(came_from: &HashMap<IVec3, IVec3>, mut current: IVec3) -> Vec<IVec3> {
+    let mut path = vec![current];
+    while let Some(&prev) = came_from.get(&current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+    path
+}
+
+/// A* search over `graph` from `start` to `goal`. Both endpoints must already be walkable nodes;
+/// edge cost and the heuristic both use `VOXEL_SIZE_METERS` times straight-line distance, so the
+/// heuristic is admissible and the search is optimal.
+pub fn find_path(graph: &NavGraph, start: IVec3, goal: IVec3) -> Option<Vec<IVec3>> {
+    if !graph.is_walkable(start) || !graph.is_walkable(goal) {
+        return None;
+    }
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    let mut open = BinaryHeap::new();
+    open.push(ScoredNode { node: start, f_score: heuristic(start, goal) });
+    let mut came_from: HashMap<IVec3, IVec3> = HashMap::new();
+    let mut g_score: HashMap<IVec3, f32> = HashMap::new();
+    g_score.insert(start, 0.0);
+
+    while let Some(current) = open.pop() {
+        if current.node == goal {
+            return Some(reconstruct_path(&came_from, goal));
+        }
+        let current_g = g_score[&current.node];
+        for neighbor in graph.neighbors(current.node) {
+            let tentative_g = current_g + VOXEL_SIZE_METERS * (neighbor.as_vec3() - current.node.as_vec3()).length();
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&f32::INFINITY) {
+                came_from.insert(neighbor, current.node);
+                g_score.insert(neighbor, tentative_g);
+                open.push(ScoredNode { node: neighbor, f_score: tentative_g + heuristic(neighbor, goal) });
+            }
+        }
+    }
+    None
+}
+
+/// Answers queued `PathRequest`s against the current `NavGraph`
+pub fn handle_path_requests(
+    nav_graph: Res<NavGraph>,
+    mut requests: EventReader<PathRequest>,
+    mut results: EventWriter<PathResult>,
+) {
+    for request in requests.read() {
+        let waypoints = find_path(&nav_graph, request.start, request.goal);
+        results.send(PathResult { start: request.start, goal: request.goal, waypoints });
+    }
+}
+
+/// Rebuilds `NavGraph` for any chunk `chunk.rs` flagged `nav_dirty` (newly loaded, regenerated, or
+/// unloaded), and drops any chunk no longer in `ChunkManager` at all (evicted outright by
+/// `stream_chunks_around_camera`).
+pub fn sync_nav_graph(mut nav_graph: ResMut<NavGraph>, mut chunk_manager: ResMut<ChunkManager>) {
+    let loaded: HashSet<(i64, i64, i64)> = chunk_manager.loaded_chunks.keys().copied().collect();
+    let evicted: Vec<(i64, i64, i64)> = nav_graph
+        .by_chunk
+        .keys()
+        .filter(|pos| !loaded.contains(pos))
+        .copied()
+        .collect();
+    for pos in evicted {
+        nav_graph.invalidate_chunk(pos);
+    }
+
+    for (&pos, managed_chunk) in chunk_manager.loaded_chunks.iter_mut() {
+        if !managed_chunk.nav_dirty {
+            continue;
+        }
+        match &managed_chunk.chunk {
+            Some(chunk) => nav_graph.rebuild_chunk(pos, chunk),
+            None => nav_graph.invalidate_chunk(pos),
+        }
+        managed_chunk.nav_dirty = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_chunk_graph(chunk: &Chunk) -> NavGraph {
+        let mut graph = NavGraph::default();
+        graph.rebuild_chunk((0, 0, 0), chunk);
+        graph
+    }
+
+    #[test]
+    fn flat_floor_yields_straight_path() {
+        let mut chunk = Chunk::new_filled(Voxel::Air);
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                chunk.voxels[x][0][z] = Voxel::Solid;
+            }
+        }
+        let graph = single_chunk_graph(&chunk);
+
+        let start = IVec3::new(1, 1, 1);
+        let goal = IVec3::new(5, 1, 1);
+        let path = find_path(&graph, start, goal).expect("flat floor should be traversable");
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.iter().all(|node| node.y == 1));
+    }
+
+    #[test]
+    fn single_step_up_is_traversable() {
+        let mut chunk = Chunk::new_filled(Voxel::Air);
+        for z in 0..CHUNK_SIZE {
+            for x in 0..3 {
+                chunk.voxels[x][0][z] = Voxel::Solid;
+            }
+            for x in 3..CHUNK_SIZE {
+                chunk.voxels[x][1][z] = Voxel::Solid;
+            }
+        }
+        let graph = single_chunk_graph(&chunk);
+
+        let start = IVec3::new(1, 1, 5);
+        let goal = IVec3::new(5, 2, 5);
+        let path = find_path(&graph, start, goal).expect("single step up should be traversable");
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        // The path has to cross the step, so it must visit both floor heights
+        assert!(path.iter().any(|node| node.y == 1));
+        assert!(path.iter().any(|node| node.y == 2));
+    }
+
+    #[test]
+    fn chasm_wider_than_one_step_has_no_path() {
+        let mut chunk = Chunk::new_filled(Voxel::Air);
+        for z in 0..CHUNK_SIZE {
+            for x in 0..3 {
+                chunk.voxels[x][0][z] = Voxel::Solid;
+            }
+            // x in 3..6 is left as a bottomless gap, too wide for a single-step transition
+            for x in 6..9 {
+                chunk.voxels[x][0][z] = Voxel::Solid;
+            }
+        }
+        let graph = single_chunk_graph(&chunk);
+
+        let start = IVec3::new(1, 1, 5);
+        let goal = IVec3::new(7, 1, 5);
+        assert!(find_path(&graph, start, goal).is_none());
+    }
+
+    #[test]
+    fn unwalkable_endpoint_has_no_path() {
+        let chunk = Chunk::new_filled(Voxel::Air);
+        let graph = single_chunk_graph(&chunk);
+        assert!(find_path(&graph, IVec3::new(1, 1, 1), IVec3::new(2, 1, 1)).is_none());
+    }
+
+    #[test]
+    fn ordinary_terrain_surface_voxels_give_footing() {
+        // `WorldGen`/`decorate_chunk` only ever emit `Voxel::Solid` below sea level; everything
+        // above it is a surface voxel like `Grass`/`Dirt`/`Stone`/`Sand`. Footing must work on
+        // those too, or `NavGraph` is empty for all normal above-water terrain.
+        let mut chunk = Chunk::new_filled(Voxel::Air);
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                chunk.voxels[x][0][z] = Voxel::Grass;
+            }
+        }
+        let graph = single_chunk_graph(&chunk);
+
+        let start = IVec3::new(1, 1, 1);
+        let goal = IVec3::new(5, 1, 1);
+        let path = find_path(&graph, start, goal).expect("grass surface should give footing");
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn water_surface_gives_no_footing() {
+        let mut chunk = Chunk::new_filled(Voxel::Air);
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                chunk.voxels[x][0][z] = Voxel::Water;
+            }
+        }
+        let graph = single_chunk_graph(&chunk);
+        assert!(find_path(&graph, IVec3::new(1, 1, 1), IVec3::new(5, 1, 1)).is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+
+use crate::chunk::ChunkManager;
+use crate::input::{ActionState, AxisAction, ButtonAction};
+use crate::terrain::{Chunk, Voxel, CHUNK_SIZE, VOXEL_SIZE_METERS};
+
+/// Half-width/half-height of the player's axis-aligned collision box, in meters
+const PLAYER_HALF_WIDTH: f32 = 0.3;
+const PLAYER_HALF_HEIGHT: f32 = 0.9;
+
+const GRAVITY: f32 = -20.0;
+const JUMP_SPEED: f32 = 7.0;
+const WALK_SPEED: f32 = 5.0;
+const DEFAULT_FLY_SPEED: f32 = 5.0;
+
+/// Attached to the camera entity. Tracks fall velocity, ground contact, and the walk/fly toggle so
+/// `player_movement_system` knows whether to resolve voxel collisions or move freely like before.
+#[derive(Component)]
+pub struct PlayerController {
+    pub velocity: Vec3,
+    pub grounded: bool,
+    pub flying: bool,
+    /// Free-fly-mode movement speed, adjusted by `camera::camera_zoom`'s mouse wheel handling
+    pub fly_speed: f32,
+}
+
+impl Default for PlayerController {
+    fn default() -> Self {
+        Self { velocity: Vec3::ZERO, grounded: false, flying: true, fly_speed: DEFAULT_FLY_SPEED }
+    }
+}
+
+/// Looks up the voxel at a world position, converting meters to the chunk/local-voxel coordinates
+/// `chunk.rs`'s own coordinate math uses
+fn voxel_at_world(chunk_manager: &ChunkManager, world_pos: Vec3) -> Voxel {
+    let vx = (world_pos.x / VOXEL_SIZE_METERS).floor() as i64;
+    let vy = (world_pos.y / VOXEL_SIZE_METERS).floor() as i64;
+    let vz = (world_pos.z / VOXEL_SIZE_METERS).floor() as i64;
+    let chunk_pos = (
+        vx.div_euclid(CHUNK_SIZE as i64),
+        vy.div_euclid(CHUNK_SIZE as i64),
+        vz.div_euclid(CHUNK_SIZE as i64),
+    );
+    let local = (
+        vx.rem_euclid(CHUNK_SIZE as i64) as usize,
+        vy.rem_euclid(CHUNK_SIZE as i64) as usize,
+        vz.rem_euclid(CHUNK_SIZE as i64) as usize,
+    );
+    chunk_manager
+        .loaded_chunks
+        .get(&chunk_pos)
+        .and_then(|managed_chunk| managed_chunk.chunk.as_ref())
+        .map(|chunk: &Chunk| chunk.voxels[local.0][local.1][local.2])
+        .unwrap_or(Voxel::Air)
+}
+
+/// True if any corner of the player's AABB, centered at `center`, overlaps a solid cell (see
+/// `Voxel::is_solid`)
+fn aabb_overlaps_solid(chunk_manager: &ChunkManager, center: Vec3) -> bool {
+    for &dx in &[-PLAYER_HALF_WIDTH, PLAYER_HALF_WIDTH] {
+        for &dy in &[-PLAYER_HALF_HEIGHT, PLAYER_HALF_HEIGHT] {
+            for &dz in &[-PLAYER_HALF_WIDTH, PLAYER_HALF_WIDTH] {
+                let corner = center + Vec3::new(dx, dy, dz);
+                if voxel_at_world(chunk_manager, corner).is_solid() {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Moves the camera each `FixedUpdate` tick. While `flying`, moves freely along its own facing
+/// (the old behavior). Otherwise applies gravity and resolves collisions against solid cells (see
+/// `Voxel::is_solid`) one axis at a time (X, then Z, then Y), zeroing velocity and flagging
+/// `grounded` when the downward move is blocked.
+pub fn player_movement_system(
+    action_state: Res<ActionState>,
+    chunk_manager: Res<ChunkManager>,
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &mut PlayerController, &crate::camera::CameraController)>,
+) {
+    for (mut transform, mut controller, camera_controller) in query.iter_mut() {
+        // In orbit mode `camera::camera_orbit_pan` owns WASD (it pans the orbit focus instead),
+        // so skip this system's movement entirely to avoid applying the same input twice.
+        if camera_controller.mode == crate::camera::CameraMode::Orbit {
+            continue;
+        }
+
+        if action_state.just_pressed(ButtonAction::ToggleFly) {
+            controller.flying = !controller.flying;
+            controller.velocity = Vec3::ZERO;
+        }
+
+        let move_input = Vec3::new(
+            action_state.axis(AxisAction::MoveStrafe),
+            action_state.axis(AxisAction::MoveVertical),
+            action_state.axis(AxisAction::MoveForwardBackward),
+        );
+
+        if controller.flying {
+            if move_input.length_squared() > 0.0 {
+                let forward = transform.forward();
+                let right = transform.right();
+                let mut move_vec = Vec3::ZERO;
+                move_vec += forward * -move_input.z;
+                move_vec += right * move_input.x;
+                move_vec += Vec3::Y * move_input.y;
+                transform.translation += move_vec.normalize() * time.delta_secs() * controller.fly_speed;
+            }
+            controller.velocity = Vec3::ZERO;
+            controller.grounded = false;
+            continue;
+        }
+
+        controller.velocity.y += GRAVITY * time.delta_secs();
+        if controller.grounded && action_state.just_pressed(ButtonAction::Jump) {
+            controller.velocity.y = JUMP_SPEED;
+        }
+
+        let forward = transform.forward();
+        let right = transform.right();
+        let forward_flat = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+        let right_flat = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
+        let mut horizontal = Vec3::ZERO;
+        if move_input.x != 0.0 || move_input.z != 0.0 {
+            horizontal += forward_flat * -move_input.z;
+            horizontal += right_flat * move_input.x;
+            horizontal = horizontal.normalize_or_zero() * WALK_SPEED;
+        }
+
+        let mut delta = Vec3::new(horizontal.x, controller.velocity.y, horizontal.z) * time.delta_secs();
+        let mut pos = transform.translation;
+
+        // Resolve X
+        let try_pos = pos + Vec3::new(delta.x, 0.0, 0.0);
+        if aabb_overlaps_solid(&chunk_manager, try_pos) {
+            delta.x = 0.0;
+        } else {
+            pos.x = try_pos.x;
+        }
+
+        // Resolve Z
+        let try_pos = pos + Vec3::new(0.0, 0.0, delta.z);
+        if aabb_overlaps_solid(&chunk_manager, try_pos) {
+            delta.z = 0.0;
+        } else {
+            pos.z = try_pos.z;
+        }
+
+        // Resolve Y
+        controller.grounded = false;
+        let try_pos = pos + Vec3::new(0.0, delta.y, 0.0);
+        if aabb_overlaps_solid(&chunk_manager, try_pos) {
+            if delta.y < 0.0 {
+                controller.grounded = true;
+            }
+            controller.velocity.y = 0.0;
+        } else {
+            pos.y = try_pos.y;
+        }
+
+        transform.translation = pos;
+    }
+}
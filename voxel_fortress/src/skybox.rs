@@ -0,0 +1,77 @@
+use bevy::asset::LoadState;
+use bevy::core_pipeline::Skybox;
+use bevy::prelude::*;
+use bevy::render::render_resource::{TextureViewDescriptor, TextureViewDimension};
+
+use crate::input::{ActionState, ButtonAction};
+
+/// Stacked-cubemap image paths `Cubemap` cycles through, swapped by index (e.g. day/night)
+const CUBEMAP_PATHS: [&str; 2] = ["skybox/day.png", "skybox/night.png"];
+
+/// Tracks which cubemap is loaded and whether it still needs the one-time `TextureViewDimension::Cube`
+/// reinterpretation `asset_server` can't apply until the image has actually finished loading
+#[derive(Resource)]
+pub struct Cubemap {
+    pub index: usize,
+    pub image_handle: Handle<Image>,
+    pub is_loaded: bool,
+}
+
+/// Kicks off the cubemap load; must run before `setup` spawns the camera, since `setup` reads
+/// `cubemap.image_handle` to build the `Skybox` component it attaches
+pub fn setup_skybox(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Cubemap {
+        index: 0,
+        image_handle: asset_server.load(CUBEMAP_PATHS[0]),
+        is_loaded: false,
+    });
+}
+
+/// Builds the `Skybox` component for the camera spawned in `setup`, pointed at whichever cubemap
+/// handle is currently loading (or loaded); `reinterpret_loaded_cubemap` swaps in the real cube
+/// view once the asset finishes loading
+pub fn skybox_component(cubemap: &Cubemap) -> Skybox {
+    Skybox { image: cubemap.image_handle.clone(), brightness: 1000.0, ..default() }
+}
+
+/// Once the cubemap image finishes loading, reinterprets its stacked 2D layers as a
+/// `TextureViewDimension::Cube` array and pushes the now-valid handle onto every `Skybox`
+pub fn reinterpret_loaded_cubemap(
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut cubemap: ResMut<Cubemap>,
+    mut skyboxes: Query<&mut Skybox>,
+) {
+    if cubemap.is_loaded || asset_server.load_state(&cubemap.image_handle) != LoadState::Loaded {
+        return;
+    }
+
+    let image = images.get_mut(&cubemap.image_handle).unwrap();
+    if image.texture_descriptor.array_layer_count() == 1 {
+        image.reinterpret_stacked_2d_as_array(image.height() / image.width());
+        image.texture_view_descriptor = Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..default()
+        });
+    }
+
+    for mut skybox in skyboxes.iter_mut() {
+        skybox.image = cubemap.image_handle.clone();
+    }
+    cubemap.is_loaded = true;
+}
+
+/// Cycles to the next cubemap in `CUBEMAP_PATHS` on `ButtonAction::CycleSkybox`, kicking off a
+/// fresh load that `reinterpret_loaded_cubemap` picks up once it completes
+pub fn cycle_skybox(
+    action_state: Res<ActionState>,
+    asset_server: Res<AssetServer>,
+    mut cubemap: ResMut<Cubemap>,
+) {
+    if !action_state.just_pressed(ButtonAction::CycleSkybox) {
+        return;
+    }
+    cubemap.index = (cubemap.index + 1) % CUBEMAP_PATHS.len();
+    cubemap.image_handle = asset_server.load(CUBEMAP_PATHS[cubemap.index]);
+    cubemap.is_loaded = false;
+}
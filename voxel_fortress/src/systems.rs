@@ -1,13 +1,188 @@
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::math::{DVec3, EulerRot};
+use bevy::pbr::{light_consts, CascadeShadowConfigBuilder};
 use bevy::prelude::*;
-use crate::chunk::ChunkManager;
+use bevy::winit::{UpdateMode, WinitSettings};
+use std::collections::HashMap;
+use std::time::Duration;
+use crate::chunk::{ChunkManager, ChunkState};
 use crate::terrain::{CHUNK_SIZE, VOXEL_SIZE_METERS};
+use crate::ChunkMaterialHandle;
+
+/// How long a reactive frame waits for the next device/user/window event before redrawing anyway,
+/// as a last-resort heartbeat
+const REACTIVE_WAIT: Duration = Duration::from_millis(250);
+
+/// Forces continuous rendering (`Performance`) or lets the render loop idle when nothing is
+/// happening (`LowPower`, the default). A voxel builder spends long stretches with a static camera
+/// and a fully streamed-in world, so `LowPower` is the right default; `Performance` exists for
+/// anyone who'd rather Winit never skip a frame (e.g. while profiling).
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PowerMode {
+    Performance,
+    #[default]
+    LowPower,
+}
+
+/// Snapshot of every loaded chunk's `ChunkState`, compared frame-to-frame by `update_power_mode` to
+/// detect chunk creation/removal/LOD transitions without threading a dirty flag through
+/// `update_chunk_entities_system` or `chunk::update_chunk_lod_system`.
+#[derive(Resource, Default)]
+pub struct ChunkStateSnapshot(HashMap<(i64, i64, i64), ChunkState>);
+
+/// Switches Winit between continuous and reactive updating based on `PowerMode` and whatever
+/// activity is detected this frame. `PowerMode::Performance` always forces continuous updates;
+/// `PowerMode::LowPower` only does while a chunk was created/removed/changed LOD this frame, or
+/// there was keyboard/mouse input - otherwise it lets the loop idle between events.
+pub fn update_power_mode(
+    power_mode: Res<PowerMode>,
+    mut winit_settings: ResMut<WinitSettings>,
+    mut snapshot: ResMut<ChunkStateSnapshot>,
+    chunk_manager: Res<ChunkManager>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+) {
+    let current: HashMap<(i64, i64, i64), ChunkState> = chunk_manager
+        .loaded_chunks
+        .iter()
+        .map(|(&pos, managed_chunk)| (pos, managed_chunk.state))
+        .collect();
+    let chunk_activity = current != snapshot.0;
+    snapshot.0 = current;
+
+    let had_input = keyboard.is_changed()
+        || mouse_buttons.is_changed()
+        || mouse_motion.read().next().is_some()
+        || mouse_wheel.read().next().is_some();
+
+    let continuous = *power_mode == PowerMode::Performance || chunk_activity || had_input;
+    let desired_mode = if continuous {
+        UpdateMode::Continuous
+    } else {
+        UpdateMode::Reactive {
+            wait: REACTIVE_WAIT,
+            react_to_device_events: true,
+            react_to_user_events: true,
+            react_to_window_events: true,
+        }
+    };
+
+    if winit_settings.focused_mode != desired_mode {
+        winit_settings.focused_mode = desired_mode;
+    }
+}
 
 #[derive(Component)]
 pub struct LoadingBar;
 
-// Resource to store the chunk material handle
-#[derive(Resource, Clone)]
-pub struct ChunkMaterialHandle(pub Handle<StandardMaterial>);
+/// How detailed the rendering pipeline is allowed to be. `Low` keeps weak hardware running by
+/// skipping the bloom post-process and shadow-casting; `High` enables both.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RenderQuality {
+    Low,
+    #[default]
+    High,
+}
+
+impl RenderQuality {
+    pub fn hdr_enabled(self) -> bool {
+        self == RenderQuality::High
+    }
+
+    fn shadows_enabled(self) -> bool {
+        self == RenderQuality::High
+    }
+}
+
+/// Default chunk load radius (the default of `GameConfig::chunk_load_radius`), used here to size the
+/// sun's shadow cascades. The cascades do not follow a load radius overridden in the config file.
+const RENDER_RADIUS_CHUNKS: i32 = 5;
+
+/// Spawns the directional "sun" light with shadow cascades sized to `RENDER_RADIUS_CHUNKS`, so both
+/// nearby and distant terrain receive shadows. Shadow casting itself is gated by `RenderQuality`.
+pub fn setup_world_lighting(mut commands: Commands, render_quality: Res<RenderQuality>) {
+    let render_radius_meters = RENDER_RADIUS_CHUNKS as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS;
+    commands.spawn((
+        DirectionalLight {
+            shadows_enabled: render_quality.shadows_enabled(),
+            illuminance: light_consts::lux::AMBIENT_DAYLIGHT,
+            ..default()
+        },
+        CascadeShadowConfigBuilder {
+            num_cascades: 4,
+            maximum_distance: render_radius_meters,
+            ..default()
+        }
+        .build(),
+        Transform::from_rotation(Quat::from_euler(
+            EulerRot::ZYX,
+            0.0,
+            -std::f32::consts::FRAC_PI_4,
+            -std::f32::consts::FRAC_PI_4,
+        )),
+    ));
+}
+
+/// Marks an entity spawned by `update_chunk_entities_system` as a chunk's mesh, distinguishing it
+/// from the camera and anything else in the scene
+#[derive(Component)]
+pub struct ChunkEntity;
+
+/// How far (in meters) the camera may drift from the render origin before `rebase_floating_origin`
+/// shifts everything back. One chunk width keeps the shift small enough to be visually unnoticeable
+/// while still happening rarely.
+const REBASE_THRESHOLD_METERS: f32 = CHUNK_SIZE as f32 * VOXEL_SIZE_METERS;
+
+/// Accumulated world-space offset (in meters) that has been subtracted from every rendered
+/// `Transform` so far. An entity's true, precision-stable world position is always
+/// `transform.translation as f64 + offset`; kept in `DVec3` because f32 alone loses enough
+/// precision past a few thousand meters to visibly jitter chunk geometry.
+///
+/// Invariant: after `rebase_floating_origin` runs, the camera's `Transform.translation` is within
+/// `REBASE_THRESHOLD_METERS` of the origin. Chunk entities are shifted by the same delta, so each
+/// keeps its offset from the camera, which can be as large as the chunk load radius.
+#[derive(Resource, Default)]
+pub struct FloatingOrigin {
+    pub offset: DVec3,
+}
+
+/// Returns the delta to subtract from every rendered transform (and fold into
+/// `FloatingOrigin::offset`) once the camera's render-space translation exceeds
+/// `REBASE_THRESHOLD_METERS`, or `None` if no rebase is needed yet. Split out from
+/// `rebase_floating_origin` so the rebase math can be exercised without spinning up an `App`.
+fn rebase_delta(camera_translation: Vec3) -> Option<Vec3> {
+    if camera_translation.length() > REBASE_THRESHOLD_METERS {
+        Some(camera_translation)
+    } else {
+        None
+    }
+}
+
+/// Runs each frame after the camera has had a chance to move. Once the camera has drifted more
+/// than one chunk width from the render origin, shifts the camera and every spawned chunk entity
+/// back toward the origin by that same delta and folds it into `FloatingOrigin::offset`, so
+/// rendered coordinates near the camera always stay small regardless of how far the world has been
+/// explored.
+pub fn rebase_floating_origin(
+    mut floating_origin: ResMut<FloatingOrigin>,
+    mut camera_query: Query<&mut Transform, With<Camera3d>>,
+    mut chunk_query: Query<&mut Transform, (With<ChunkEntity>, Without<Camera3d>)>,
+) {
+    let Ok(mut camera_transform) = camera_query.get_single_mut() else {
+        return;
+    };
+    let Some(delta) = rebase_delta(camera_transform.translation) else {
+        return;
+    };
+
+    floating_origin.offset += delta.as_dvec3();
+    camera_transform.translation -= delta;
+    for mut chunk_transform in chunk_query.iter_mut() {
+        chunk_transform.translation -= delta;
+    }
+}
 
 pub fn loading_progress_ui(
     chunk_manager: Res<ChunkManager>,
@@ -21,30 +196,51 @@ pub fn loading_progress_ui(
     }
 }
 
-// Setup function to create and store the material handle as a resource
-pub fn setup_chunk_material(
-    mut commands: Commands,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    let handle = materials.add(StandardMaterial::default());
-    commands.insert_resource(ChunkMaterialHandle(handle));
-}
-
+/// Spawns or despawns the mesh entity for each loaded chunk. A chunk whose entity would currently
+/// fall outside the camera's view frustum is left without one (or has its existing one despawned)
+/// rather than being built/kept purely off-screen.
 pub fn update_chunk_entities_system(
     mut commands: Commands,
     mut chunk_manager: ResMut<crate::chunk::ChunkManager>,
-    mut meshes: ResMut<Assets<Mesh>>,
     chunk_material: Res<ChunkMaterialHandle>,
+    floating_origin: Res<FloatingOrigin>,
+    camera_query: Query<(&Transform, &Projection), With<Camera3d>>,
 ) {
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+    // View-projection matrix is identical for every chunk this frame, so build the frustum planes
+    // once rather than per-chunk
+    let view = camera_transform.compute_matrix().inverse();
+    let view_projection = projection.get_projection_matrix() * view;
+    let frustum_planes = frustum_planes_from_view_projection(view_projection);
+
+    let chunk_size_meters = CHUNK_SIZE as f32 * VOXEL_SIZE_METERS;
     for (pos, managed_chunk) in chunk_manager.loaded_chunks.iter_mut() {
-        let should_have_entity = match managed_chunk.state {
-            crate::chunk::ChunkState::Active => managed_chunk.chunk.is_some(),
-            crate::chunk::ChunkState::LOD => managed_chunk.lod_mesh.is_some(),
-            crate::chunk::ChunkState::Unloaded => false,
-        };
+        // Mesh building itself happens off the main thread in `ChunkMeshBuilder`'s worker pool
+        // (mesh_builder.rs); this system only spawns the entity once a built mesh handle lands.
+        // Computed in f64 from the authoritative i64 chunk coordinates and rebased by
+        // `FloatingOrigin::offset`, so a freshly (re)spawned chunk entity always lines up with ones
+        // that were already shifted by a prior rebase.
+        let chunk_world_origin = DVec3::new(
+            pos.0 as f64 * chunk_size_meters as f64,
+            pos.1 as f64 * chunk_size_meters as f64,
+            pos.2 as f64 * chunk_size_meters as f64,
+        );
+        let chunk_position = (chunk_world_origin - floating_origin.offset).as_vec3();
+        let chunk_center = chunk_position + Vec3::splat(chunk_size_meters / 2.0);
+        let chunk_radius = chunk_size_meters * 0.87; // Approximation for chunk diagonal
+        let is_visible = is_sphere_in_frustum(&frustum_planes, chunk_center, chunk_radius);
+
+        let should_have_entity = is_visible
+            && match managed_chunk.state {
+                crate::chunk::ChunkState::Active => managed_chunk.built_mesh.is_some(),
+                crate::chunk::ChunkState::LOD => managed_chunk.lod_mesh.is_some(),
+                crate::chunk::ChunkState::Unloaded => false,
+            };
         if should_have_entity && managed_chunk.entity.is_none() {
             let mesh_handle = match managed_chunk.state {
-                crate::chunk::ChunkState::Active => managed_chunk.chunk.as_ref().map(|c| meshes.add(c.to_mesh())),
+                crate::chunk::ChunkState::Active => managed_chunk.built_mesh.clone(),
                 crate::chunk::ChunkState::LOD => managed_chunk.lod_mesh.clone(),
                 _ => None,
             };
@@ -52,11 +248,8 @@ pub fn update_chunk_entities_system(
                 let entity = commands.spawn((
                     Mesh3d(mesh),
                     MeshMaterial3d(chunk_material.0.clone()),
-                    Transform::from_xyz(
-                        pos.0 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS,
-                        pos.1 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS,
-                        pos.2 as f32 * CHUNK_SIZE as f32 * VOXEL_SIZE_METERS,
-                    ),
+                    Transform::from_translation(chunk_position),
+                    ChunkEntity,
                 )).id();
                 managed_chunk.entity = Some(entity);
             }
@@ -67,4 +260,120 @@ pub fn update_chunk_entities_system(
             }
         }
     }
+}
+
+/// One plane of the view frustum in world space, normalized so that `signed_distance` gives the
+/// true distance (in meters) from a point to the plane along its outward normal.
+struct FrustumPlane {
+    normal: Vec3,
+    d: f32,
+}
+
+impl FrustumPlane {
+    /// Builds a plane from a row (or row sum/difference) of a view-projection matrix, per the
+    /// Gribb-Hartmann method, normalizing `(a, b, c, d)` by `length(a, b, c)`.
+    fn from_row(row: Vec4) -> Self {
+        let normal = row.truncate();
+        let len = normal.length();
+        Self { normal: normal / len, d: row.w / len }
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+}
+
+/// Extracts the six view-frustum planes (left, right, bottom, top, near, far) from a combined
+/// view-projection matrix, per the Gribb-Hartmann method.
+fn frustum_planes_from_view_projection(view_projection: Mat4) -> [FrustumPlane; 6] {
+    let row1 = view_projection.row(0);
+    let row2 = view_projection.row(1);
+    let row3 = view_projection.row(2);
+    let row4 = view_projection.row(3);
+    [
+        FrustumPlane::from_row(row4 + row1), // left
+        FrustumPlane::from_row(row4 - row1), // right
+        FrustumPlane::from_row(row4 + row2), // bottom
+        FrustumPlane::from_row(row4 - row2), // top
+        FrustumPlane::from_row(row4 + row3), // near
+        FrustumPlane::from_row(row4 - row3), // far
+    ]
+}
+
+/// True if the bounding sphere at `sphere_center`/`sphere_radius` intersects or lies inside every
+/// frustum plane.
+fn is_sphere_in_frustum(planes: &[FrustumPlane; 6], sphere_center: Vec3, sphere_radius: f32) -> bool {
+    planes.iter().all(|plane| plane.signed_distance(sphere_center) >= -sphere_radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A standard perspective projection (60 deg vertical FOV, 16:9, near 0.1, far 100), looking
+    // down -Z from the origin, so view = identity and view_projection = projection alone.
+    fn test_frustum() -> [FrustumPlane; 6] {
+        let projection = Mat4::perspective_rh(60f32.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+        frustum_planes_from_view_projection(projection)
+    }
+
+    #[test]
+    fn point_on_view_axis_within_range_is_inside_every_plane() {
+        let planes = test_frustum();
+        assert!(is_sphere_in_frustum(&planes, Vec3::new(0.0, 0.0, -10.0), 0.0));
+    }
+
+    #[test]
+    fn point_behind_near_plane_is_culled() {
+        let planes = test_frustum();
+        assert!(!is_sphere_in_frustum(&planes, Vec3::new(0.0, 0.0, -0.01), 0.0));
+    }
+
+    #[test]
+    fn point_beyond_far_plane_is_culled() {
+        let planes = test_frustum();
+        assert!(!is_sphere_in_frustum(&planes, Vec3::new(0.0, 0.0, -200.0), 0.0));
+    }
+
+    #[test]
+    fn point_far_off_axis_is_culled_by_side_planes() {
+        let planes = test_frustum();
+        assert!(!is_sphere_in_frustum(&planes, Vec3::new(1000.0, 0.0, -10.0), 0.0));
+    }
+
+    #[test]
+    fn sphere_radius_extends_the_outside_edge_back_inside() {
+        let planes = test_frustum();
+        // Just past the far plane for a point sphere, but its radius brings it back in range.
+        assert!(is_sphere_in_frustum(&planes, Vec3::new(0.0, 0.0, -100.5), 1.0));
+    }
+
+    #[test]
+    fn rebase_keeps_render_translation_small_over_1e6_meters_of_travel() {
+        let mut offset = DVec3::ZERO;
+        let mut camera_translation = Vec3::ZERO;
+
+        // Advance in 37 m steps; each one exceeds the 8 m rebase threshold, so every step rebases
+        // and the whole distance traveled accumulates in the f64 offset.
+        let step = 37.0_f32;
+        let total_steps = (1_000_000.0 / step as f64) as i64;
+        for _ in 0..total_steps {
+            camera_translation.x += step;
+            if let Some(delta) = rebase_delta(camera_translation) {
+                offset += delta.as_dvec3();
+                camera_translation -= delta;
+            }
+        }
+
+        // Invariant: the rendered translation never wanders past the rebase threshold...
+        assert!(camera_translation.length() <= REBASE_THRESHOLD_METERS);
+
+        // ...while reconstructing the true world position from the f64 offset plus the small f32
+        // render translation still matches the distance actually traveled, to within a fraction of
+        // one step - the whole point of keeping `offset` in f64 instead of letting
+        // `camera_translation` alone accumulate 1e6 meters of f32 rounding error.
+        let true_world_x = camera_translation.x as f64 + offset.x;
+        let expected_world_x = total_steps as f64 * step as f64;
+        assert!((true_world_x - expected_world_x).abs() < 1.0);
+    }
 }
\ No newline at end of file
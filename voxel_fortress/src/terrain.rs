@@ -20,6 +20,23 @@ pub enum Voxel {
     Taiga,
     Desert,
     Snow,
+    Water,
+    OreCoal,
+    OreIron,
+    OreGold,
+    Wood,
+    Leaves,
+    Ice,
+}
+
+impl Voxel {
+    /// True for any voxel that blocks movement and provides footing. Terrain surfaces are
+    /// `Stone`/`Dirt`/`Grass`/`Sand`/`Snow`/`Ice`/ore/etc, not just the underwater-only `Solid`
+    /// variant, so collision and pathfinding must check this instead of `== Voxel::Solid`. `Water`
+    /// is excluded - it's walked/swum through, not stood on top of.
+    pub fn is_solid(self) -> bool {
+        !matches!(self, Voxel::Air | Voxel::Water)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,9 +46,17 @@ pub enum ChunkLodState {
     Unloaded, // Not loaded/generated
 }
 
+#[derive(Clone)]
 pub struct Chunk {
     pub voxels: [[[Voxel; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
     pub lod_state: ChunkLodState, // Track LOD state for this chunk
+    /// Per-column biome blend weights from `WorldGen::get_biome_blended`, set by `from_worldgen`
+    /// when `WorldGen::biome_blend` is enabled; `to_mesh` uses these to fade surface color
+    /// across biome boundaries instead of a hard 1-voxel seam
+    pub biome_weights: Option<[[[(u8, f32); 4]; CHUNK_SIZE]; CHUNK_SIZE]>,
+    /// Flowing-liquid level per voxel, 0-8 (8 = source); only meaningful where `voxels` holds
+    /// `Voxel::Water`, maintained by `settle_liquids`
+    pub liquid_levels: [[[u8; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
 }
 
 impl Chunk {
@@ -39,6 +64,8 @@ impl Chunk {
         Self {
             voxels: [[[voxel; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
             lod_state: ChunkLodState::Active,
+            biome_weights: None,
+            liquid_levels: [[[0u8; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE],
         }
     }
     // Simple heightmap-based chunk generation
@@ -62,25 +89,42 @@ impl Chunk {
         let base_x = chunk_pos.0 * CHUNK_SIZE as i64;
         let base_y = chunk_pos.1 * CHUNK_SIZE as i64;
         let base_z = chunk_pos.2 * CHUNK_SIZE as i64;
-        
+
+        if worldgen.biome_blend {
+            chunk.biome_weights = Some([[[(0u8, 0.0f32); 4]; CHUNK_SIZE]; CHUNK_SIZE]);
+        }
+
         // Use 3D noise for terrain generation with proper features
         for x in 0..CHUNK_SIZE {
             for z in 0..CHUNK_SIZE {
                 let wx = base_x + x as i64;
                 let wz = base_z + z as i64;
-                
+
                 // Get surface height at this x,z coordinate
                 let surface_height = worldgen.get_height(wx as f64, wz as f64);
-                let biome = worldgen.get_biome(wx as f64, wz as f64);
-                
+
+                // With biome blending enabled, `from_worldgen` still picks the voxel type from
+                // the dominant biome; the weights are stashed per-column so `to_mesh` can fade
+                // the surface color between the contributing biomes instead
+                let biome = if worldgen.biome_blend {
+                    let (dominant, weights) = worldgen.get_biome_blended(wx as f64, wz as f64);
+                    if let Some(biome_weights) = chunk.biome_weights.as_mut() {
+                        biome_weights[x][z] = weights;
+                    }
+                    dominant
+                } else {
+                    worldgen.get_biome(wx as f64, wz as f64)
+                };
+
                 // Water level (sea level is at y=0)
                 let water_level = 0.0;
-                
+                let river_depth = worldgen.get_river_depth(wx as f64, wz as f64);
+
                 // Fill in this column of voxels using true 3D noise
                 for y in 0..CHUNK_SIZE {
                     let wy = base_y + y as i64;
                     let world_y = wy as f64;
-                    
+
                     // For underwater terrain, use a simpler system
                     if world_y < water_level {
                         // Simple underwater terrain
@@ -88,140 +132,484 @@ impl Chunk {
                             // Underwater ground
                             chunk.voxels[x][y][z] = Voxel::Solid;
                         } else {
-                            // Water will be handled separately in the future
-                            chunk.voxels[x][y][z] = Voxel::Air;
+                            // Source water; settle_liquids below spreads it into any open Air
+                            chunk.voxels[x][y][z] = Voxel::Water;
+                            chunk.liquid_levels[x][y][z] = 8;
                         }
                     } else {
                         // Above water, use full 3D density function for terrain
                         let density = worldgen.get_density(wx as f64, world_y, wz as f64, surface_height);
-                        
+
                         // Positive density means solid terrain
                         if density > 0.0 {
-                            // Set voxel type based on biome and height
-                            chunk.voxels[x][y][z] = match biome {
-                                0 => Voxel::Forest, // Forest
-                                1 => Voxel::Plains, // Plains
-                                2 => Voxel::Taiga,  // Taiga
-                                3 => Voxel::Desert, // Desert
-                                _ => Voxel::Stone,
-                            };
+                            if river_depth > 0.0 && world_y <= water_level + 2.0 {
+                                // Riverbank: sand instead of the biome's usual surface voxel
+                                chunk.voxels[x][y][z] = Voxel::Sand;
+                            } else {
+                                // Set voxel type based on biome and height
+                                chunk.voxels[x][y][z] = match biome {
+                                    0 => Voxel::Forest, // Forest
+                                    1 => Voxel::Plains, // Plains
+                                    2 => Voxel::Taiga,  // Taiga
+                                    3 => Voxel::Desert, // Desert
+                                    _ => Voxel::Stone,
+                                };
+                            }
+                        } else if river_depth > 0.0 && world_y <= water_level {
+                            // Carved channel below sea level but above the valley floor: river water
+                            chunk.voxels[x][y][z] = Voxel::Water;
+                            chunk.liquid_levels[x][y][z] = 8;
+                        }
+                    }
+                }
+
+                // Snow/ice overlay (MGV6_SNOWBIOMES): cap the column's exposed surface once its
+                // full height is known, rather than guessing mid-column
+                if worldgen.snow_biomes {
+                    if let Some(top_y) = (0..CHUNK_SIZE).rev().find(|&y| chunk.voxels[x][y][z] != Voxel::Air) {
+                        let top_wy = base_y + top_y as i64;
+                        let temp = worldgen.get_temperature(wx as f64, top_wy as f64, wz as f64);
+                        let cold = temp < 0.25 || (top_wy as f64) > worldgen.snowline;
+                        if cold {
+                            match chunk.voxels[x][top_y][z] {
+                                Voxel::Water => chunk.voxels[x][top_y][z] = Voxel::Ice,
+                                Voxel::Forest | Voxel::Plains | Voxel::Taiga | Voxel::Desert
+                                | Voxel::Dirt | Voxel::Grass | Voxel::Sand | Voxel::Solid | Voxel::Stone => {
+                                    chunk.voxels[x][top_y][z] = Voxel::Snow;
+                                    if top_y + 1 < CHUNK_SIZE && chunk.voxels[x][top_y + 1][z] == Voxel::Air {
+                                        chunk.voxels[x][top_y + 1][z] = Voxel::Snow;
+                                    }
+                                }
+                                _ => {}
+                            }
                         }
                     }
                 }
             }
         }
+        chunk.settle_liquids();
         chunk
     }
 
-    /// Generate a mesh for all visible faces in this chunk (naive greedy meshing)
+    /// Spreads every `Voxel::Water` source cell outward via a BFS queue, mirroring Minetest's
+    /// transforming-liquid queue: each popped liquid voxel fills an Air voxel directly below it
+    /// with full-level water, then fills horizontally-adjacent Air voxels one level lower,
+    /// stopping once a branch reaches level 0. Returns the `(x, y, z)` of every voxel it filled,
+    /// so callers can re-mesh just the affected region after editing terrain.
+    pub fn settle_liquids(&mut self) -> Vec<(usize, usize, usize)> {
+        use std::collections::VecDeque;
+
+        const SOURCE_LEVEL: u8 = 8;
+        let mut changed = Vec::new();
+        let mut queue: VecDeque<(usize, usize, usize)> = VecDeque::new();
+
+        for x in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for z in 0..CHUNK_SIZE {
+                    if self.voxels[x][y][z] == Voxel::Water {
+                        queue.push_back((x, y, z));
+                    }
+                }
+            }
+        }
+
+        while let Some((x, y, z)) = queue.pop_front() {
+            let level = self.liquid_levels[x][y][z];
+            if level == 0 {
+                continue;
+            }
+
+            if y > 0 && self.voxels[x][y - 1][z] == Voxel::Air {
+                self.voxels[x][y - 1][z] = Voxel::Water;
+                self.liquid_levels[x][y - 1][z] = SOURCE_LEVEL;
+                changed.push((x, y - 1, z));
+                queue.push_back((x, y - 1, z));
+            }
+
+            let spread_level = level - 1;
+            for (dx, dz) in [(-1isize, 0isize), (1, 0), (0, -1), (0, 1)] {
+                let (nx, nz) = (x as isize + dx, z as isize + dz);
+                if nx < 0 || nz < 0 || nx >= CHUNK_SIZE as isize || nz >= CHUNK_SIZE as isize {
+                    continue;
+                }
+                let (nx, nz) = (nx as usize, nz as usize);
+                if self.voxels[nx][y][nz] == Voxel::Air {
+                    self.voxels[nx][y][nz] = Voxel::Water;
+                    self.liquid_levels[nx][y][nz] = spread_level;
+                    changed.push((nx, y, nz));
+                    queue.push_back((nx, y, nz));
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Computes a baked 0-15 light level per voxel, mirroring Minetest's node light bake: sunlight
+    /// floods straight down through open Air columns at full strength, then a breadth-first queue
+    /// spreads `max(neighbor_light) - 1` into adjacent Air voxels so overhangs and cave mouths
+    /// darken gradually. Point emitters (a future `Voxel::Torch`-style voxel, seeded at level 14)
+    /// would feed into the same queue. Light is chunk-local for now; the queue-based design means
+    /// cross-chunk seams can later be stitched by re-seeding this chunk's boundary voxels with
+    /// light levels read from its neighbors before draining the queue.
+    pub fn compute_light(&self) -> [[[u8; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE] {
+        use std::collections::VecDeque;
+
+        let mut light = [[[0u8; CHUNK_SIZE]; CHUNK_SIZE]; CHUNK_SIZE];
+        let mut queue: VecDeque<(usize, usize, usize)> = VecDeque::new();
+
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                for y in (0..CHUNK_SIZE).rev() {
+                    if self.voxels[x][y][z] != Voxel::Air {
+                        break;
+                    }
+                    light[x][y][z] = 15;
+                    queue.push_back((x, y, z));
+                }
+            }
+        }
+
+        while let Some((x, y, z)) = queue.pop_front() {
+            let level = light[x][y][z];
+            if level <= 1 {
+                continue;
+            }
+            let spread = level - 1;
+            let neighbors: [(isize, isize, isize); 6] = [
+                (x as isize - 1, y as isize, z as isize),
+                (x as isize + 1, y as isize, z as isize),
+                (x as isize, y as isize - 1, z as isize),
+                (x as isize, y as isize + 1, z as isize),
+                (x as isize, y as isize, z as isize - 1),
+                (x as isize, y as isize, z as isize + 1),
+            ];
+            for (nx, ny, nz) in neighbors {
+                if nx < 0 || ny < 0 || nz < 0
+                    || nx >= CHUNK_SIZE as isize || ny >= CHUNK_SIZE as isize || nz >= CHUNK_SIZE as isize
+                {
+                    continue;
+                }
+                let (nx, ny, nz) = (nx as usize, ny as usize, nz as usize);
+                if self.voxels[nx][ny][nz] != Voxel::Air || light[nx][ny][nz] >= spread {
+                    continue;
+                }
+                light[nx][ny][nz] = spread;
+                queue.push_back((nx, ny, nz));
+            }
+        }
+
+        light
+    }
+
+    /// Returns the y of the topmost non-Air voxel in column (x, z), if any
+    fn column_surface(&self, x: usize, z: usize) -> Option<usize> {
+        (0..CHUNK_SIZE).rev().find(|&y| self.voxels[x][y][z] != Voxel::Air)
+    }
+
+    /// Slides surface soil downhill so steep slopes don't leave blocky dirt cliffs (mirrors
+    /// Minetest's MGV6_MUDFLOW). Runs after `from_worldgen`: for each column's topmost soil
+    /// voxel, moves one voxel onto any horizontal neighbor whose surface sits at least 2 voxels
+    /// lower, turning the vacated voxel into whatever was beneath it. Iterates until no column
+    /// moves or `MAX_PASSES` is hit, so a single steep slope can cascade over several passes
+    /// without ever looking outside this chunk.
+    pub fn apply_mudflow(&mut self) {
+        const MAX_PASSES: u32 = 4;
+        const NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+        for _ in 0..MAX_PASSES {
+            let mut changed = false;
+
+            for x in 0..CHUNK_SIZE {
+                for z in 0..CHUNK_SIZE {
+                    let Some(y) = self.column_surface(x, z) else { continue };
+                    if !is_soil(self.voxels[x][y][z]) {
+                        continue;
+                    }
+
+                    for (dx, dz) in NEIGHBORS {
+                        let (nx, nz) = (x as isize + dx, z as isize + dz);
+                        if nx < 0 || nz < 0 || nx >= CHUNK_SIZE as isize || nz >= CHUNK_SIZE as isize {
+                            continue;
+                        }
+                        let (nx, nz) = (nx as usize, nz as usize);
+
+                        // The neighbor's surface is, by definition, already exposed to Air above it
+                        let neighbor_y = self.column_surface(nx, nz).map_or(-1, |y| y as isize);
+                        if (y as isize) - neighbor_y < 2 {
+                            continue;
+                        }
+                        let target_y = (neighbor_y + 1) as usize;
+                        if target_y >= CHUNK_SIZE {
+                            continue;
+                        }
+
+                        let soil = self.voxels[x][y][z];
+                        let beneath = if y > 0 { self.voxels[x][y - 1][z] } else { Voxel::Air };
+                        self.voxels[x][y][z] = beneath;
+                        self.voxels[nx][target_y][nz] = soil;
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Computes the lit surface color for the face of `voxels[x][y][z]` facing `face_type`
+    /// ("top"/"bottom"/"side"), matching the palette the naive per-face mesher used to inline
+    fn face_color(&self, x: usize, y: usize, z: usize, face_type: &str) -> [f32; 4] {
+        let voxel = self.voxels[x][y][z];
+        let above = if y + 1 < CHUNK_SIZE { self.voxels[x][y + 1][z] } else { Voxel::Air };
+        let t = y as f32 / (CHUNK_SIZE as f32 - 1.0);
+
+        let stone_color = [0.5, 0.5, 0.5, 1.0];
+        let dirt_color = [0.45, 0.32, 0.18, 1.0];
+        let grass_side_color = [0.45, 0.32, 0.18, 1.0];
+        let grass_top_color = [0.2, 0.8, 0.2, 1.0];
+        let sand_color = [0.76, 0.7, 0.5, 1.0];
+        let forest_color = [0.15, 0.6, 0.15, 1.0];
+        let plains_color = [0.3, 0.65, 0.3, 1.0];
+        let taiga_color = [0.2, 0.5, 0.4, 1.0];
+        let desert_color = [0.85, 0.75, 0.5, 1.0];
+        let snow_color = [0.9, 0.9, 0.95, 1.0];
+        let ore_coal_color = [0.15, 0.15, 0.17, 1.0];
+        let ore_iron_color = [0.7, 0.55, 0.45, 1.0];
+        let ore_gold_color = [0.85, 0.7, 0.2, 1.0];
+        let wood_color = [0.35, 0.22, 0.1, 1.0];
+        let leaves_color = [0.18, 0.45, 0.15, 1.0];
+        let ice_color = [0.75, 0.85, 0.95, 0.85];
+
+        // With biome blending enabled, fade the top surface color across the biomes
+        // contributing to this column instead of snapping hard at the dominant biome's border
+        if face_type == "top" && matches!(voxel, Voxel::Forest | Voxel::Plains | Voxel::Taiga | Voxel::Desert) {
+            if let Some(biome_weights) = &self.biome_weights {
+                let weights = biome_weights[x][z];
+                let mut blended = [0.0f32; 4];
+                for &(biome, weight) in &weights {
+                    if weight <= 0.0 {
+                        continue;
+                    }
+                    let contribution = biome_surface_color(biome);
+                    for channel in 0..4 {
+                        blended[channel] += contribution[channel] * weight;
+                    }
+                }
+                return blended;
+            }
+        }
+
+        match voxel {
+            Voxel::Stone => stone_color,
+            Voxel::Dirt => dirt_color,
+            Voxel::Grass => {
+                // Grass has green top, dirt sides
+                match face_type {
+                    "top" => grass_top_color,
+                    _ => grass_side_color,
+                }
+            }
+            Voxel::Sand => sand_color,
+            Voxel::Forest => match face_type {
+                "top" => forest_color,
+                _ => dirt_color,
+            },
+            Voxel::Plains => match face_type {
+                "top" => plains_color,
+                _ => dirt_color,
+            },
+            Voxel::Taiga => match face_type {
+                "top" => taiga_color,
+                _ => dirt_color,
+            },
+            Voxel::Desert => desert_color,
+            Voxel::Snow => snow_color,
+            Voxel::Water => [0.2, 0.4, 0.9, 0.6],
+            Voxel::OreCoal => ore_coal_color,
+            Voxel::OreIron => ore_iron_color,
+            Voxel::OreGold => ore_gold_color,
+            Voxel::Wood => wood_color,
+            Voxel::Leaves => leaves_color,
+            Voxel::Ice => ice_color,
+            // Default for Solid or any other voxel type
+            _ => {
+                let brown = [0.45, 0.32, 0.18, 1.0];
+                let green = [0.2, 0.8, 0.2, 1.0];
+                match face_type {
+                    "top" if above == Voxel::Air => green,
+                    "side" => [
+                        brown[0] * (1.0 - t) + green[0] * t,
+                        brown[1] * (1.0 - t) + green[1] * t,
+                        brown[2] * (1.0 - t) + green[2] * t,
+                        1.0,
+                    ],
+                    _ => brown,
+                }
+            }
+        }
+    }
+
+    /// Generate a mesh for all visible faces in this chunk using true greedy meshing: each of
+    /// the 6 face directions is swept slice-by-slice, and coplanar faces sharing a voxel type
+    /// and color are merged into a single quad before being emitted
     pub fn to_mesh(&self) -> Mesh {
         let mut positions = Vec::new();
         let mut normals = Vec::new();
         let mut colors = Vec::new();
         let mut indices = Vec::new();
         let mut uvs = Vec::new();
-        let mut i = 0u32;
 
+        let size = VOXEL_SIZE_METERS;
+        let light = self.compute_light();
+        // Light level of the neighbor a face opens into, out-of-chunk treated as unlit for now
+        // (see `compute_light`'s doc comment on cross-chunk stitching)
+        let light_at = |x: isize, y: isize, z: isize| -> u8 {
+            if x < 0 || y < 0 || z < 0
+                || x >= CHUNK_SIZE as isize || y >= CHUNK_SIZE as isize || z >= CHUNK_SIZE as isize
+            {
+                0
+            } else {
+                light[x as usize][y as usize][z as usize]
+            }
+        };
+        // Scales a base palette color by a voxel's light level, with a small ambient floor so
+        // fully-shadowed faces stay dimly visible instead of going pure black
+        let shaded = |color: [f32; 4], light_level: u8| -> [f32; 4] {
+            let factor = (light_level as f32 / 15.0).max(0.15);
+            [color[0] * factor, color[1] * factor, color[2] * factor, color[3]]
+        };
+
+        // -Z / +Z: sweep along z, mask axes are (x, y)
+        for z in 0..CHUNK_SIZE {
+            let mut mask_neg: [[Option<FaceCell>; CHUNK_SIZE]; CHUNK_SIZE] = [[None; CHUNK_SIZE]; CHUNK_SIZE];
+            let mut mask_pos = mask_neg;
+            for x in 0..CHUNK_SIZE {
+                for y in 0..CHUNK_SIZE {
+                    if self.voxels[x][y][z] == Voxel::Air {
+                        continue;
+                    }
+                    if z == 0 || self.voxels[x][y][z - 1] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "side"), light_at(x as isize, y as isize, z as isize - 1));
+                        mask_neg[x][y] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: 1.0, uv_rect: atlas_uv_rect(self.voxels[x][y][z], "side") });
+                    }
+                    if z + 1 == CHUNK_SIZE || self.voxels[x][y][z + 1] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "side"), light_at(x as isize, y as isize, z as isize + 1));
+                        mask_pos[x][y] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: 1.0, uv_rect: atlas_uv_rect(self.voxels[x][y][z], "side") });
+                    }
+                }
+            }
+            greedy_merge_mask(&mask_neg, |x, y, w, h, cell| {
+                let (bx, by, bz) = (x as f32 * size, y as f32 * size, z as f32 * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx + wf, by, bz),
+                    Vec3::new(bx + wf, by + hf, bz),
+                    Vec3::new(bx, by + hf, bz),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [0.0, 0.0, -1.0], cell.color, cell.uv_rect, false);
+            });
+            greedy_merge_mask(&mask_pos, |x, y, w, h, cell| {
+                let (bx, by, bz) = (x as f32 * size, y as f32 * size, (z as f32 + 1.0) * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx + wf, by, bz),
+                    Vec3::new(bx + wf, by + hf, bz),
+                    Vec3::new(bx, by + hf, bz),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [0.0, 0.0, 1.0], cell.color, cell.uv_rect, true);
+            });
+        }
+
+        // -Y / +Y: sweep along y, mask axes are (x, z)
+        for y in 0..CHUNK_SIZE {
+            let mut mask_neg: [[Option<FaceCell>; CHUNK_SIZE]; CHUNK_SIZE] = [[None; CHUNK_SIZE]; CHUNK_SIZE];
+            let mut mask_pos = mask_neg;
+            for x in 0..CHUNK_SIZE {
+                for z in 0..CHUNK_SIZE {
+                    if self.voxels[x][y][z] == Voxel::Air {
+                        continue;
+                    }
+                    if y == 0 || self.voxels[x][y - 1][z] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "bottom"), light_at(x as isize, y as isize - 1, z as isize));
+                        mask_neg[x][z] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: 1.0, uv_rect: atlas_uv_rect(self.voxels[x][y][z], "bottom") });
+                    }
+                    if y + 1 == CHUNK_SIZE || self.voxels[x][y + 1][z] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "top"), light_at(x as isize, y as isize + 1, z as isize));
+                        mask_pos[x][z] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: top_height(self.voxels[x][y][z], self.liquid_levels[x][y][z]), uv_rect: atlas_uv_rect(self.voxels[x][y][z], "top") });
+                    }
+                }
+            }
+            greedy_merge_mask(&mask_neg, |x, z, w, h, cell| {
+                let (bx, by, bz) = (x as f32 * size, y as f32 * size, z as f32 * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx + wf, by, bz),
+                    Vec3::new(bx + wf, by, bz + hf),
+                    Vec3::new(bx, by, bz + hf),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [0.0, -1.0, 0.0], cell.color, cell.uv_rect, false);
+            });
+            greedy_merge_mask(&mask_pos, |x, z, w, h, cell| {
+                let (bx, by, bz) = (x as f32 * size, (y as f32 + cell.top_height) * size, z as f32 * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx, by, bz + hf),
+                    Vec3::new(bx + wf, by, bz + hf),
+                    Vec3::new(bx + wf, by, bz),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [0.0, 1.0, 0.0], cell.color, cell.uv_rect, false);
+            });
+        }
+
+        // -X / +X: sweep along x, mask axes are (y, z)
         for x in 0..CHUNK_SIZE {
+            let mut mask_neg: [[Option<FaceCell>; CHUNK_SIZE]; CHUNK_SIZE] = [[None; CHUNK_SIZE]; CHUNK_SIZE];
+            let mut mask_pos = mask_neg;
             for y in 0..CHUNK_SIZE {
                 for z in 0..CHUNK_SIZE {
-                    if self.voxels[x][y][z] == Voxel::Solid {
-                        for (dx, dy, dz, normal, face_type) in [
-                            (0, 0, -1, [0.0, 0.0, -1.0], "side"),
-                            (0, 0, 1, [0.0, 0.0, 1.0], "side"),
-                            (0, -1, 0, [0.0, -1.0, 0.0], "bottom"),
-                            (0, 1, 0, [0.0, 1.0, 0.0], "top"),
-                            (-1, 0, 0, [-1.0, 0.0, 0.0], "side"),
-                            (1, 0, 0, [1.0, 0.0, 0.0], "side"),
-                        ] {
-                            let nx = x as isize + dx;
-                            let ny = y as isize + dy;
-                            let nz = z as isize + dz;
-                            let neighbor = if nx < 0 || ny < 0 || nz < 0 ||
-                                nx >= CHUNK_SIZE as isize || ny >= CHUNK_SIZE as isize || nz >= CHUNK_SIZE as isize {
-                                Voxel::Air
-                            } else {
-                                self.voxels[nx as usize][ny as usize][nz as usize]
-                            };
-                            if neighbor == Voxel::Air {
-                                let base = Vec3::new(x as f32, y as f32, z as f32) * VOXEL_SIZE_METERS;
-                                let (face_verts, face_normals, face_indices) = face_mesh(base, normal, i);
-                                positions.extend(face_verts.iter().cloned());
-                                normals.extend(face_normals.iter().cloned());
-                                uvs.extend(vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
-
-                                // Get terrain colors based on voxel type and face
-                                let above = if y + 1 < CHUNK_SIZE { self.voxels[x][y + 1][z] } else { Voxel::Air };
-                                let t = y as f32 / (CHUNK_SIZE as f32 - 1.0);
-                                
-                                // Define colors for different voxel types
-                                let stone_color = [0.5, 0.5, 0.5, 1.0];
-                                let dirt_color = [0.45, 0.32, 0.18, 1.0];
-                                let grass_side_color = [0.45, 0.32, 0.18, 1.0]; 
-                                let grass_top_color = [0.2, 0.8, 0.2, 1.0];
-                                let sand_color = [0.76, 0.7, 0.5, 1.0];
-                                let forest_color = [0.15, 0.6, 0.15, 1.0];
-                                let plains_color = [0.3, 0.65, 0.3, 1.0];
-                                let taiga_color = [0.2, 0.5, 0.4, 1.0];
-                                let desert_color = [0.85, 0.75, 0.5, 1.0];
-                                let snow_color = [0.9, 0.9, 0.95, 1.0];
-                                
-                                // Select color based on voxel type and face
-                                let face_color = match self.voxels[x][y][z] {
-                                    Voxel::Stone => stone_color,
-                                    Voxel::Dirt => dirt_color,
-                                    Voxel::Grass => {
-                                        // Grass has green top, dirt sides
-                                        match face_type {
-                                            "top" => grass_top_color,
-                                            _ => grass_side_color,
-                                        }
-                                    },
-                                    Voxel::Sand => sand_color,
-                                    Voxel::Forest => {
-                                        match face_type {
-                                            "top" => forest_color,
-                                            _ => dirt_color,
-                                        }
-                                    },
-                                    Voxel::Plains => {
-                                        match face_type {
-                                            "top" => plains_color,
-                                            _ => dirt_color,
-                                        }
-                                    },
-                                    Voxel::Taiga => {
-                                        match face_type {
-                                            "top" => taiga_color,
-                                            _ => dirt_color,
-                                        }
-                                    },
-                                    Voxel::Desert => desert_color,
-                                    Voxel::Snow => snow_color,
-                                    // Default for Solid or any other voxel type
-                                    _ => {
-                                        let brown = [0.45, 0.32, 0.18, 1.0];
-                                        let green = [0.2, 0.8, 0.2, 1.0];
-                                        match face_type {
-                                            "top" if above == Voxel::Air => green,
-                                            "side" => [
-                                                brown[0] * (1.0 - t) + green[0] * t,
-                                                brown[1] * (1.0 - t) + green[1] * t,
-                                                brown[2] * (1.0 - t) + green[2] * t,
-                                                1.0
-                                            ],
-                                            _ => brown,
-                                        }
-                                    },
-                                };
-                                colors.extend([face_color; 4]);
-                                indices.extend(face_indices.iter().cloned());
-                                i += 4;
-                            }
-                        }
+                    if self.voxels[x][y][z] == Voxel::Air {
+                        continue;
+                    }
+                    if x == 0 || self.voxels[x - 1][y][z] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "side"), light_at(x as isize - 1, y as isize, z as isize));
+                        mask_neg[y][z] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: 1.0, uv_rect: atlas_uv_rect(self.voxels[x][y][z], "side") });
+                    }
+                    if x + 1 == CHUNK_SIZE || self.voxels[x + 1][y][z] == Voxel::Air {
+                        let lit = shaded(self.face_color(x, y, z, "side"), light_at(x as isize + 1, y as isize, z as isize));
+                        mask_pos[y][z] = Some(FaceCell { voxel: self.voxels[x][y][z], color: lit, top_height: 1.0, uv_rect: atlas_uv_rect(self.voxels[x][y][z], "side") });
                     }
                 }
             }
+            greedy_merge_mask(&mask_neg, |y, z, w, h, cell| {
+                let (bx, by, bz) = (x as f32 * size, y as f32 * size, z as f32 * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx, by + wf, bz),
+                    Vec3::new(bx, by + wf, bz + hf),
+                    Vec3::new(bx, by, bz + hf),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [-1.0, 0.0, 0.0], cell.color, cell.uv_rect, false);
+            });
+            greedy_merge_mask(&mask_pos, |y, z, w, h, cell| {
+                let (bx, by, bz) = ((x as f32 + 1.0) * size, y as f32 * size, z as f32 * size);
+                let (wf, hf) = (w as f32 * size, h as f32 * size);
+                let corners = [
+                    Vec3::new(bx, by, bz),
+                    Vec3::new(bx, by, bz + hf),
+                    Vec3::new(bx, by + wf, bz + hf),
+                    Vec3::new(bx, by + wf, bz),
+                ];
+                push_quad(&mut positions, &mut normals, &mut colors, &mut uvs, &mut indices, corners, [1.0, 0.0, 0.0], cell.color, cell.uv_rect, false);
+            });
         }
 
         if positions.is_empty() {
@@ -258,7 +646,7 @@ impl Chunk {
             for z in 0..CHUNK_SIZE {
                 let mut max_y = None;
                 for y in (0..CHUNK_SIZE).rev() {
-                    if self.voxels[x][y][z] == Voxel::Solid {
+                    if self.voxels[x][y][z].is_solid() {
                         max_y = Some(y);
                         break;
                     }
@@ -301,6 +689,174 @@ impl Chunk {
     }
 }
 
+/// Fraction of a full voxel height the `+Y` face should sit at: 1.0 for everything except
+/// non-source `Voxel::Water`, whose surface follows its flowing liquid level
+fn top_height(voxel: Voxel, liquid_level: u8) -> f32 {
+    if voxel == Voxel::Water && liquid_level < 8 {
+        liquid_level as f32 / 8.0
+    } else {
+        1.0
+    }
+}
+
+/// Columns/rows of the voxel texture atlas `atlas_uv_rect` selects tiles from. The startup
+/// `VoxelAtlas` resource (see atlas.rs) builds its `TextureAtlasLayout` from the same grid, so the
+/// two stay in sync without `to_mesh` (which runs off the main thread, see mesh_builder.rs) ever
+/// touching a loaded asset.
+pub const ATLAS_COLUMNS: u32 = 4;
+pub const ATLAS_ROWS: u32 = 4;
+
+/// Which atlas tile a voxel's face samples, grouped the same way the ASCII `Renderer`'s height
+/// bands are (water, sand, grass/soil, stone), extended with the voxel types added since
+fn atlas_tile_index(voxel: Voxel, face_type: &str) -> u32 {
+    match voxel {
+        Voxel::Stone => 0,
+        Voxel::Dirt => 1,
+        Voxel::Grass => match face_type {
+            "top" => 2,
+            _ => 1,
+        },
+        Voxel::Sand => 3,
+        Voxel::Forest => match face_type {
+            "top" => 4,
+            _ => 1,
+        },
+        Voxel::Plains => match face_type {
+            "top" => 5,
+            _ => 1,
+        },
+        Voxel::Taiga => match face_type {
+            "top" => 6,
+            _ => 1,
+        },
+        Voxel::Desert => 7,
+        Voxel::Snow => 8,
+        Voxel::Water => 9,
+        Voxel::OreCoal => 10,
+        Voxel::OreIron => 11,
+        Voxel::OreGold => 12,
+        Voxel::Wood => 13,
+        Voxel::Leaves => 14,
+        Voxel::Ice => 15,
+        // Default for Solid or any other voxel type: top mirrors grass, sides mirror dirt
+        _ => match face_type {
+            "top" => 2,
+            _ => 1,
+        },
+    }
+}
+
+/// The quad-corner UV coordinates for a voxel face's atlas tile, in the same corner order
+/// `push_quad` expects its `corners` parameter in
+fn atlas_uv_rect(voxel: Voxel, face_type: &str) -> [[f32; 2]; 4] {
+    let index = atlas_tile_index(voxel, face_type);
+    let col = (index % ATLAS_COLUMNS) as f32;
+    let row = (index / ATLAS_COLUMNS) as f32;
+    let u0 = col / ATLAS_COLUMNS as f32;
+    let v0 = row / ATLAS_ROWS as f32;
+    let u1 = u0 + 1.0 / ATLAS_COLUMNS as f32;
+    let v1 = v0 + 1.0 / ATLAS_ROWS as f32;
+    [[u0, v0], [u1, v0], [u1, v1], [u0, v1]]
+}
+
+/// Whether a voxel type counts as loose soil that `Chunk::apply_mudflow` can slide downhill
+fn is_soil(voxel: Voxel) -> bool {
+    matches!(voxel, Voxel::Dirt | Voxel::Grass | Voxel::Forest | Voxel::Plains | Voxel::Taiga)
+}
+
+/// Top-surface color for a `WorldGen::get_biome`/`get_biome_blended` index, used both to color
+/// a column's dominant biome and, under biome blending, as a blend contribution
+fn biome_surface_color(biome: u8) -> [f32; 4] {
+    match biome {
+        0 => [0.15, 0.6, 0.15, 1.0],  // Forest
+        1 => [0.3, 0.65, 0.3, 1.0],   // Plains
+        2 => [0.2, 0.5, 0.4, 1.0],    // Taiga
+        3 => [0.85, 0.75, 0.5, 1.0],  // Desert
+        _ => [0.5, 0.5, 0.5, 1.0],    // Stone fallback
+    }
+}
+
+/// A merged quad's voxel type, shaded color, and (for the +Y pass only) how far up the voxel
+/// the top face sits, as a fraction of a full voxel height; two mask cells only merge during
+/// greedy meshing when every field matches exactly
+#[derive(Clone, Copy, PartialEq)]
+struct FaceCell {
+    voxel: Voxel,
+    color: [f32; 4],
+    /// 1.0 for a full-height face; lower for a non-source `Voxel::Water` top, whose surface
+    /// sits at `liquid_level / 8` so flowing edges read as shallower than source water
+    top_height: f32,
+    /// UV corners of this face's atlas tile, from `atlas_uv_rect`
+    uv_rect: [[f32; 2]; 4],
+}
+
+/// Runs the standard 2D greedy-mesh merge over a `CHUNK_SIZE` x `CHUNK_SIZE` visibility mask:
+/// scans unmerged cells in row-major order, extends each rectangle along `u` while cells match,
+/// then along `v` while the whole candidate row matches, and invokes `emit` once per merged
+/// rectangle with its origin cell, size, and shared value
+fn greedy_merge_mask(
+    mask: &[[Option<FaceCell>; CHUNK_SIZE]; CHUNK_SIZE],
+    mut emit: impl FnMut(usize, usize, usize, usize, FaceCell),
+) {
+    let mut consumed = [[false; CHUNK_SIZE]; CHUNK_SIZE];
+    for v in 0..CHUNK_SIZE {
+        for u in 0..CHUNK_SIZE {
+            if consumed[u][v] {
+                continue;
+            }
+            let Some(cell) = mask[u][v] else { continue };
+
+            let mut w = 1;
+            while u + w < CHUNK_SIZE && !consumed[u + w][v] && mask[u + w][v] == Some(cell) {
+                w += 1;
+            }
+
+            let mut h = 1;
+            'grow_height: while v + h < CHUNK_SIZE {
+                for du in 0..w {
+                    if consumed[u + du][v + h] || mask[u + du][v + h] != Some(cell) {
+                        break 'grow_height;
+                    }
+                }
+                h += 1;
+            }
+
+            for row in consumed.iter_mut().skip(u).take(w) {
+                for c in row.iter_mut().skip(v).take(h) {
+                    *c = true;
+                }
+            }
+
+            emit(u, v, w, h, cell);
+        }
+    }
+}
+
+/// Pushes one merged quad's vertex attributes and winds its two triangles
+fn push_quad(
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    colors: &mut Vec<[f32; 4]>,
+    uvs: &mut Vec<[f32; 2]>,
+    indices: &mut Vec<u32>,
+    corners: [Vec3; 4],
+    normal: [f32; 3],
+    color: [f32; 4],
+    uv_rect: [[f32; 2]; 4],
+    flip_winding: bool,
+) {
+    let i = positions.len() as u32;
+    positions.extend(corners.iter().map(|c| [c.x, c.y, c.z]));
+    normals.extend([normal; 4]);
+    colors.extend([color; 4]);
+    uvs.extend(uv_rect);
+    if flip_winding {
+        indices.extend([i, i + 2, i + 1, i, i + 3, i + 2]);
+    } else {
+        indices.extend([i, i + 1, i + 2, i, i + 2, i + 3]);
+    }
+}
+
 fn face_mesh(base: Vec3, normal: [f32; 3], i: u32) -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<u32>) {
     // Returns the 4 vertices, normals, and 6 indices for a quad face at base with the given normal
     let (dx, dy, dz) = (normal[0], normal[1], normal[2]);
@@ -399,6 +955,27 @@ pub struct WorldGen {
     // Additional noise generators for enhanced terrain
     pub warp_x_noise: Perlin,
     pub warp_z_noise: Perlin,
+    /// 3D noise channel sampled by `decorate_chunk`'s ore pass; kept separate from `cave_noise`
+    /// so ore blobs don't correlate with cave shapes
+    pub ore_noise: Perlin,
+    /// Low-frequency noise channel `get_river_depth` reads; valleys follow its zero-crossings
+    /// (mirrors the Valleys mapgen's river noise)
+    pub river_noise: Perlin,
+    /// When set, `get_height`/`get_density` carve river valleys and `from_worldgen` fills them
+    /// with `Voxel::Water` up to `water_level`
+    pub rivers: bool,
+    /// When set, `from_worldgen` caps cold or high-altitude columns with `Voxel::Snow` and
+    /// freezes their surface water into `Voxel::Ice` (mirrors Minetest's MGV6_SNOWBIOMES)
+    pub snow_biomes: bool,
+    /// World altitude above which a column snow-caps regardless of temperature, so tall peaks
+    /// get snowcaps even at warm latitudes
+    pub snowline: f64,
+    /// When set, `Chunk::from_worldgen` samples `get_biome_blended` instead of `get_biome`, so
+    /// biome borders fade over several voxels rather than snapping at a 1-voxel seam
+    pub biome_blend: bool,
+    /// Offset in meters, along each horizontal axis, of the 4 diagonal samples `get_biome_blended`
+    /// takes around a column
+    pub blend_radius: f64,
 }
 
 impl WorldGen {
@@ -414,6 +991,13 @@ impl WorldGen {
             cave_noise: Perlin::new(seed as u32 + 4),
             warp_x_noise: Perlin::new(seed as u32 + 5),
             warp_z_noise: Perlin::new(seed as u32 + 6),
+            ore_noise: Perlin::new(seed as u32 + 7),
+            river_noise: Perlin::new(seed as u32 + 8),
+            rivers: false,
+            snow_biomes: false,
+            snowline: 140.0,
+            biome_blend: false,
+            blend_radius: 8.0,
         }
     }
     
@@ -451,16 +1035,45 @@ impl WorldGen {
         
         // Final height calculation with smoothing between features
         let height = base_terrain + mountains_contribution + hills;
-        
-        height
+
+        // Carve river valleys into the raw terrain height (mirrors the Valleys mapgen)
+        height - self.get_river_depth(x, z)
     }
-    
+
+    /// Depth to carve out of the surface height at world `(x, z)` for river/valley terrain, 0.0
+    /// where no channel reaches. Follows the zero-crossings of a low-frequency noise ridge: where
+    /// `|river_noise| < river_width` the surface is pulled down toward `water_level` with a smooth
+    /// profile, so the channel reads as a continuous winding lowland rather than a canyon with
+    /// hard walls. Gated behind `rivers` so existing worlds without it are unaffected.
+    pub fn get_river_depth(&self, x: f64, z: f64) -> f64 {
+        if !self.rivers {
+            return 0.0;
+        }
+
+        let river_scale = 0.0015;
+        let river_width = 0.05;
+        let valley_depth = 40.0;
+
+        let n = self.river_noise.get([x * river_scale, z * river_scale]).abs();
+        if n < river_width {
+            (river_width - n) / river_width * valley_depth
+        } else {
+            0.0
+        }
+    }
+
     /// Get 3D density value at a specific world position (true 3D noise)
     pub fn get_density(&self, x: f64, y: f64, z: f64, surface_height: f64) -> f64 {
         // Surface gradient - more solid as we go deeper
         let depth = surface_height - y;
         let surface_density = if depth > 0.0 { depth / 5.0 } else { -1.0 };
-        
+
+        // Keep carved river channels open: within a valley, nothing above the (already-lowered)
+        // surface should be solid, even where cave/detail noise below would otherwise add density
+        if self.get_river_depth(x, z) > 0.0 && depth <= 0.0 {
+            return -1.0;
+        }
+
         // Cave systems
         let cave_scale = 0.03;
         let cave_threshold = 0.6;
@@ -495,17 +1108,9 @@ impl WorldGen {
     
     /// Get a biome index for a given world position (x, z) using climate model
     pub fn get_biome(&self, x: f64, z: f64) -> u8 {
-        // More varied climate model
-        let temp_scale = 0.0007;
         let rain_scale = 0.0005;
-        
-        // Temperature decreases with distance from equator (use abs for symmetry)
-        let equator_influence = 1.0 - (z * 0.0001).abs().min(1.0);
-        
-        // Base temperature with noise variation
-        let temp = equator_influence * 0.7 + 
-                   self.base_noise.get([x * temp_scale, 2000.0 + z * temp_scale]) * 0.3;
-        
+        let temp = self.base_temperature(x, z);
+
         // Rainfall with warping for more realistic weather patterns
         let rain_x = x + self.warp_x_noise.get([x * rain_scale * 0.5, z * rain_scale * 0.5]) * 200.0;
         let rain_z = z + self.warp_z_noise.get([x * rain_scale * 0.5 + 500.0, z * rain_scale * 0.5 + 500.0]) * 200.0;
@@ -531,4 +1136,194 @@ impl WorldGen {
             _ => 2,                             // Snow fields
         }
     }
+
+    /// Base climate temperature at world `(x, z)`, independent of altitude: colder toward the
+    /// poles with noise-driven local variation. Shared by `get_biome` and `get_temperature`.
+    fn base_temperature(&self, x: f64, z: f64) -> f64 {
+        let temp_scale = 0.0007;
+        // Temperature decreases with distance from equator (use abs for symmetry)
+        let equator_influence = 1.0 - (z * 0.0001).abs().min(1.0);
+        equator_influence * 0.7 + self.base_noise.get([x * temp_scale, 2000.0 + z * temp_scale]) * 0.3
+    }
+
+    /// Temperature at world `(x, y, z)`: the same climate model `get_biome` uses, with a
+    /// lapse-rate falloff proportional to altitude so tall mountains read as cold enough for
+    /// snowcaps regardless of latitude.
+    pub fn get_temperature(&self, x: f64, y: f64, z: f64) -> f64 {
+        const LAPSE_RATE: f64 = 0.003;
+        self.base_temperature(x, z) - y * LAPSE_RATE
+    }
+
+    /// Samples the climate model at `(x, z)` plus the 4 diagonal offsets `blend_radius` meters
+    /// away and returns the dominant biome alongside each biome's share of the 5 samples, so
+    /// callers can fade surface color across biome boundaries instead of snapping at a hard
+    /// 1-voxel seam (mirrors Minetest's MGV6_BIOMEBLEND)
+    pub fn get_biome_blended(&self, x: f64, z: f64) -> (u8, [(u8, f32); 4]) {
+        // `x`/`z` are world voxel coordinates, so convert the radius from meters to voxels
+        let r = self.blend_radius / VOXEL_SIZE_METERS as f64;
+        let samples = [
+            self.get_biome(x, z),
+            self.get_biome(x + r, z + r),
+            self.get_biome(x + r, z - r),
+            self.get_biome(x - r, z + r),
+            self.get_biome(x - r, z - r),
+        ];
+
+        let mut weights = [(0u8, 0.0f32); 4];
+        for (biome, weight) in weights.iter_mut().enumerate() {
+            let biome = biome as u8;
+            let share = samples.iter().filter(|&&s| s == biome).count() as f32 / samples.len() as f32;
+            *weight = (biome, share);
+        }
+
+        (samples[0], weights)
+    }
+
+    /// Decorates a chunk already filled by `from_worldgen` with ores, trees, and other surface
+    /// features, mirroring Minetest's mg_ore/treegen/mg_decoration passes that layer detail on
+    /// top of raw terrain. Run this after `from_worldgen` (and `apply_mudflow`, so trees root on
+    /// settled ground).
+    pub fn decorate_chunk(&self, chunk: &mut Chunk, chunk_pos: (i64, i64, i64)) {
+        self.place_ores(chunk, chunk_pos);
+        self.place_trees(chunk, chunk_pos);
+    }
+
+    /// Turns `Voxel::Stone` into clustered ore blobs: each ore in `ORE_TABLE` only appears within
+    /// its own depth band, and a dedicated 3D noise channel (`ore_noise`) thresholded per-ore
+    /// produces the blob shape rather than scattering single voxels.
+    fn place_ores(&self, chunk: &mut Chunk, chunk_pos: (i64, i64, i64)) {
+        let base_x = chunk_pos.0 * CHUNK_SIZE as i64;
+        let base_y = chunk_pos.1 * CHUNK_SIZE as i64;
+        let base_z = chunk_pos.2 * CHUNK_SIZE as i64;
+
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                let wx = base_x + x as i64;
+                let wz = base_z + z as i64;
+                let surface_height = self.get_height(wx as f64, wz as f64);
+
+                for y in 0..CHUNK_SIZE {
+                    if chunk.voxels[x][y][z] != Voxel::Stone {
+                        continue;
+                    }
+                    let wy = base_y + y as i64;
+                    let depth = surface_height - wy as f64;
+
+                    for ore in ORE_TABLE {
+                        if depth < ore.min_depth || depth > ore.max_depth {
+                            continue;
+                        }
+                        let n = self.ore_noise.get([
+                            wx as f64 * ore.noise_scale,
+                            wy as f64 * ore.noise_scale,
+                            wz as f64 * ore.noise_scale,
+                        ]);
+                        if n > ore.threshold {
+                            chunk.voxels[x][y][z] = ore.voxel;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stamps trees onto the chunk's surface. Trunk positions come from hashing the world (x, z)
+    /// column with the seed, so the decision to spawn a tree there (and its height) is identical
+    /// no matter which chunk requests it — adjacent chunks agree on a tree even when its canopy
+    /// overhangs the chunk boundary, since each chunk independently re-derives the same trunk
+    /// position and just clips whatever part of it falls outside its own bounds.
+    fn place_trees(&self, chunk: &mut Chunk, chunk_pos: (i64, i64, i64)) {
+        let base_x = chunk_pos.0 * CHUNK_SIZE as i64;
+        let base_z = chunk_pos.2 * CHUNK_SIZE as i64;
+        const CANOPY_RADIUS: i64 = 2;
+
+        // Scan a border around this chunk wide enough that a neighboring column's canopy can
+        // still stamp leaves into this chunk
+        for wx in (base_x - CANOPY_RADIUS)..(base_x + CHUNK_SIZE as i64 + CANOPY_RADIUS) {
+            for wz in (base_z - CANOPY_RADIUS)..(base_z + CHUNK_SIZE as i64 + CANOPY_RADIUS) {
+                let biome = self.get_biome(wx as f64, wz as f64);
+                // Forest/Taiga grow dense forests, Plains gets sparse scattered trees, Desert none
+                let density = match biome {
+                    0 | 2 => 10u64,
+                    1 => 80u64,
+                    _ => 0,
+                };
+                if density == 0 {
+                    continue;
+                }
+                if hash_xz(self.seed, wx, wz) % density != 0 {
+                    continue;
+                }
+
+                let surface_height = self.get_height(wx as f64, wz as f64);
+                let trunk_base_wy = surface_height.floor() as i64 + 1;
+                let trunk_height = 4 + (hash_xz(self.seed ^ 0xF00D, wx, wz) % 3) as i64;
+
+                for h in 0..trunk_height {
+                    self.stamp_voxel(chunk, chunk_pos, wx, trunk_base_wy + h, wz, Voxel::Wood);
+                }
+
+                let canopy_wy = trunk_base_wy + trunk_height - 1;
+                for dx in -CANOPY_RADIUS..=CANOPY_RADIUS {
+                    for dz in -CANOPY_RADIUS..=CANOPY_RADIUS {
+                        for dy in 0..=CANOPY_RADIUS {
+                            if dx * dx + dz * dz + dy * dy > CANOPY_RADIUS * CANOPY_RADIUS + 1 {
+                                continue;
+                            }
+                            self.stamp_voxel(chunk, chunk_pos, wx + dx, canopy_wy + dy, wz + dz, Voxel::Leaves);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Writes `voxel` at world position `(wx, wy, wz)` if it falls within `chunk_pos`'s bounds
+    /// and doesn't overwrite an existing trunk; silently clips anything outside the chunk
+    fn stamp_voxel(&self, chunk: &mut Chunk, chunk_pos: (i64, i64, i64), wx: i64, wy: i64, wz: i64, voxel: Voxel) {
+        let base_x = chunk_pos.0 * CHUNK_SIZE as i64;
+        let base_y = chunk_pos.1 * CHUNK_SIZE as i64;
+        let base_z = chunk_pos.2 * CHUNK_SIZE as i64;
+        let (lx, ly, lz) = (wx - base_x, wy - base_y, wz - base_z);
+        if lx < 0 || ly < 0 || lz < 0 || lx >= CHUNK_SIZE as i64 || ly >= CHUNK_SIZE as i64 || lz >= CHUNK_SIZE as i64 {
+            return;
+        }
+        let (lx, ly, lz) = (lx as usize, ly as usize, lz as usize);
+        if voxel == Voxel::Leaves && chunk.voxels[lx][ly][lz] == Voxel::Wood {
+            return;
+        }
+        chunk.voxels[lx][ly][lz] = voxel;
+    }
+}
+
+/// One entry in the ore-placement table: `Voxel::Stone` within `[min_depth, max_depth]` of the
+/// surface turns into `voxel` wherever the ore-noise channel at `noise_scale` exceeds `threshold`
+#[derive(Clone, Copy)]
+struct OreSpec {
+    voxel: Voxel,
+    min_depth: f64,
+    max_depth: f64,
+    threshold: f64,
+    noise_scale: f64,
+}
+
+const ORE_TABLE: [OreSpec; 3] = [
+    OreSpec { voxel: Voxel::OreCoal, min_depth: 2.0, max_depth: 60.0, threshold: 0.72, noise_scale: 0.08 },
+    OreSpec { voxel: Voxel::OreIron, min_depth: 15.0, max_depth: 100.0, threshold: 0.76, noise_scale: 0.07 },
+    OreSpec { voxel: Voxel::OreGold, min_depth: 40.0, max_depth: 160.0, threshold: 0.82, noise_scale: 0.06 },
+];
+
+/// Deterministic hash of a world (x, z) column and the world seed, used to decide tree placement
+/// without any per-chunk state so neighboring chunks agree on the same trees (splitmix64-style
+/// mixing)
+fn hash_xz(seed: u64, x: i64, z: i64) -> u64 {
+    let mut h = seed ^ 0x9E3779B97F4A7C15;
+    h ^= (x as u64).wrapping_mul(0xBF58476D1CE4E5B9);
+    h = h.wrapping_mul(0x94D049BB133111EB);
+    h ^= h >> 31;
+    h ^= (z as u64).wrapping_mul(0xD6E8FEB86659FD93);
+    h = h.wrapping_mul(0xBF58476D1CE4E5B9);
+    h ^= h >> 33;
+    h
 }